@@ -1,7 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use e_macros::value;
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq)]
 enum TestEnum {
     #[e(value = "variant_a", index = 1)]
@@ -58,7 +58,7 @@ fn benchmark_from_index(c: &mut Criterion) {
     c.bench_function("TestEnum from", |b| {
         b.iter(|| {
             for index in &indices {
-                black_box(TestEnum::from(TestEnum::VariantC { field: *index }));
+                let _ = black_box(TestEnum::try_from(*index));
             }
         })
     });
@@ -92,7 +92,7 @@ fn benchmark_try_from_value(c: &mut Criterion) {
 
 fn benchmark_variant_count(c: &mut Criterion) {
     c.bench_function("TestEnum variant_count", |b| {
-        b.iter(|| TestEnum::variant_count())
+        b.iter(TestEnum::variant_count)
     });
 }
 
@@ -0,0 +1,506 @@
+use super::case::RenameRule;
+use super::serde::SerdeMode;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::{ext::IdentExt, meta::ParseNestedMeta, punctuated::Punctuated};
+
+/// Keys accepted inside a variant's `#[e(...)]` attribute.
+const VARIANT_KEYS: &[&str] = &[
+    "value",
+    "index",
+    "alias",
+    "default_fields",
+    "other",
+    "display",
+    "pattern",
+];
+
+/// Keys accepted by `#[value(...)]`, or by `#[e(...)]` on the enum itself.
+const CONTAINER_KEYS: &[&str] = &[
+    "parse",
+    "rename_all",
+    "prefix",
+    "suffix",
+    "index_type",
+    "allow_index_override",
+    "default_fields",
+    "serde",
+    "crate",
+    "display",
+    "no_display",
+    "no_from_str",
+    "no_as_ref",
+    "no_into_repr",
+    "no_into_str",
+    "no_eq_str",
+    "no_eq_repr",
+];
+
+/// Flags accepted inside `parse(...)`.
+const PARSE_KEYS: &[&str] = &["case_insensitive", "trim", "ignore_separators"];
+
+/// Options that apply to the whole enum.
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    /// How `TryFrom<&str>` compares input with variant values, from `parse(...)`
+    pub(crate) parse: ParsePolicy,
+    /// Case conversion for values derived from variant names, from `rename_all = "..."`
+    pub(crate) rename_all: Option<RenameRule>,
+    /// Prepended to values derived from variant names, from `prefix = "..."`
+    pub(crate) prefix: Option<String>,
+    /// Appended to values derived from variant names, from `suffix = "..."`
+    pub(crate) suffix: Option<String>,
+    /// Type of indices, overriding the repr type, from `index_type = ...`
+    pub(crate) index_type: Option<syn::Path>,
+    /// Whether `#[e(index)]` may differ from the discriminant, from `allow_index_override`
+    pub(crate) allow_index_override: bool,
+    /// Whether every data variant is parsed with `Default` payloads, from `default_fields`
+    pub(crate) default_fields: bool,
+    /// Representation used by the generated Serde impls, from `serde = "..."`
+    pub(crate) serde: Option<SerdeMode>,
+    /// Path of the e-macros runtime, from `crate = "..."`
+    pub(crate) krate: Option<syn::Path>,
+    /// Standard trait impls opted out of with the `no_*` flags
+    pub(crate) skip: SkipTraits,
+    /// How to implement `Display`, from `display` or `display = "..."`; unset, `#[value]` falls
+    /// back to inferring it from a derived `Debug`
+    pub(crate) display: Option<DisplayMode>,
+    /// Whether `Display` is left to the user, from `no_display`
+    pub(crate) no_display: bool,
+    /// Whether to generate `to_serde`, set when `Serialize` is derived
+    pub(crate) to_serde: bool,
+    /// Whether to generate `from_serde`, set when `Deserialize` is derived
+    pub(crate) from_serde: bool,
+}
+
+/// Standard trait impls that are generated unless opted out of.
+#[derive(Default)]
+pub(crate) struct SkipTraits {
+    /// `FromStr`, from `no_from_str`
+    pub(crate) from_str: bool,
+    /// `AsRef<str>`, from `no_as_ref`
+    pub(crate) as_ref: bool,
+    /// `From<Enum>` for the repr type, from `no_into_repr`
+    pub(crate) into_repr: bool,
+    /// `From<&Enum>` for `&'static str`, from `no_into_str`
+    pub(crate) into_str: bool,
+    /// `PartialEq<&str>`, from `no_eq_str`
+    pub(crate) eq_str: bool,
+    /// `PartialEq<repr>`, from `no_eq_repr`
+    pub(crate) eq_repr: bool,
+}
+
+/// What a variant without a display template is formatted as.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisplayMode {
+    /// `value`: the value string
+    Value,
+    /// `index`: the index
+    Index,
+    /// `name`: the variant name
+    Name,
+}
+
+const DISPLAY_MODES: &[(&str, DisplayMode)] = &[
+    ("value", DisplayMode::Value),
+    ("index", DisplayMode::Index),
+    ("name", DisplayMode::Name),
+];
+
+/// Mirror of `e_macros::ParsePolicy`, used to generate the parser and to detect values that
+/// collide once normalized.
+#[derive(Default, Clone, Copy)]
+pub(crate) struct ParsePolicy {
+    pub(crate) case_insensitive: bool,
+    pub(crate) trim: bool,
+    pub(crate) ignore_separators: bool,
+}
+
+impl ContainerAttrs {
+    /// Parses the arguments of the `#[value(...)]` attribute.
+    pub(crate) fn parse_args(&mut self, args: TokenStream2) -> syn::Result<()> {
+        syn::parse::Parser::parse2(
+            syn::meta::parser(|meta| self.parse_meta(meta, "value")),
+            args,
+        )
+    }
+
+    /// Parses the `#[e(...)]` attributes placed on the enum itself.
+    pub(crate) fn parse_attrs(&mut self, attrs: &[syn::Attribute]) -> syn::Result<()> {
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("e")) {
+            attr.parse_nested_meta(|meta| self.parse_meta(meta, "e"))?;
+        }
+        Ok(())
+    }
+
+    fn parse_meta(&mut self, meta: ParseNestedMeta, attr: &str) -> syn::Result<()> {
+        if meta.path.is_ident("parse") {
+            meta.parse_nested_meta(|flag| {
+                if flag.path.is_ident("case_insensitive") {
+                    self.parse.case_insensitive = true;
+                } else if flag.path.is_ident("trim") {
+                    self.parse.trim = true;
+                } else if flag.path.is_ident("ignore_separators") {
+                    self.parse.ignore_separators = true;
+                } else {
+                    return Err(unknown_key(&flag, "parse", PARSE_KEYS));
+                }
+                Ok(())
+            })
+        } else if let Some(skip) = self.skip.flag(&meta.path) {
+            *skip = true;
+            Ok(())
+        } else if meta.path.is_ident("rename_all") {
+            ensure_unset(&meta, self.rename_all.is_some())?;
+            let lit: syn::LitStr = meta.value()?.parse()?;
+            self.rename_all = Some(RenameRule::from_lit(&lit)?);
+            Ok(())
+        } else if meta.path.is_ident("default_fields") {
+            self.default_fields = true;
+            Ok(())
+        } else if meta.path.is_ident("serde") {
+            ensure_unset(&meta, self.serde.is_some())?;
+            self.serde = Some(SerdeMode::from_lit(&parse_lit_str(&meta)?)?);
+            Ok(())
+        } else if meta.path.is_ident("display") {
+            ensure_unset(&meta, self.display.is_some())?;
+            if self.no_display {
+                return Err(meta.error("`display` conflicts with `no_display`"));
+            }
+            self.display = Some(if meta.input.peek(syn::Token![=]) {
+                DisplayMode::from_lit(&parse_lit_str(&meta)?)?
+            } else {
+                DisplayMode::Value
+            });
+            Ok(())
+        } else if meta.path.is_ident("no_display") {
+            if self.display.is_some() {
+                return Err(meta.error("`no_display` conflicts with `display`"));
+            }
+            self.no_display = true;
+            Ok(())
+        } else if meta.path.is_ident("crate") {
+            ensure_unset(&meta, self.krate.is_some())?;
+            let lit = parse_lit_str(&meta)?;
+            self.krate = Some(lit.parse().map_err(|_| {
+                syn::Error::new_spanned(
+                    &lit,
+                    format!(
+                        "`crate` must be a path, e.g. `crate = \"::e_macros\"`, found {:?}",
+                        lit.value()
+                    ),
+                )
+            })?);
+            Ok(())
+        } else if meta.path.is_ident("allow_index_override") {
+            self.allow_index_override = true;
+            Ok(())
+        } else if meta.path.is_ident("index_type") {
+            ensure_unset(&meta, self.index_type.is_some())?;
+            let ty: syn::Path = meta.value()?.parse()?;
+            let is_int = ty
+                .segments
+                .last()
+                .is_some_and(|last| super::INT_TYPES.iter().any(|int| last.ident == int));
+            if !is_int {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    "`index_type` must be a primitive integer type, e.g. `index_type = u16`",
+                ));
+            }
+            self.index_type = Some(ty);
+            Ok(())
+        } else if meta.path.is_ident("prefix") {
+            ensure_unset(&meta, self.prefix.is_some())?;
+            self.prefix = Some(parse_str_value(&meta)?);
+            Ok(())
+        } else if meta.path.is_ident("suffix") {
+            ensure_unset(&meta, self.suffix.is_some())?;
+            self.suffix = Some(parse_str_value(&meta)?);
+            Ok(())
+        } else {
+            Err(unknown_key(&meta, attr, CONTAINER_KEYS))
+        }
+    }
+
+    /// Path generated code refers to the e-macros runtime by, `::e_macros` unless redirected by
+    /// crates re-exporting it.
+    pub(crate) fn runtime_path(&self) -> syn::Path {
+        self.krate
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(::e_macros))
+    }
+
+    /// Derives the value of a variant without `#[e(value = "...")]` from its name.
+    pub(crate) fn derive_value(&self, ident: &syn::Ident) -> String {
+        let name = ident.unraw().to_string();
+        let name = match self.rename_all {
+            Some(rule) => rule.apply(&name),
+            None => name,
+        };
+        format!(
+            "{}{}{}",
+            self.prefix.as_deref().unwrap_or_default(),
+            name,
+            self.suffix.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+impl SkipTraits {
+    /// Returns the flag toggled by a `no_*` key.
+    fn flag(&mut self, path: &syn::Path) -> Option<&mut bool> {
+        let key = path.get_ident()?.to_string();
+        match key.as_str() {
+            "no_from_str" => Some(&mut self.from_str),
+            "no_as_ref" => Some(&mut self.as_ref),
+            "no_into_repr" => Some(&mut self.into_repr),
+            "no_into_str" => Some(&mut self.into_str),
+            "no_eq_str" => Some(&mut self.eq_str),
+            "no_eq_repr" => Some(&mut self.eq_repr),
+            _ => None,
+        }
+    }
+}
+
+impl DisplayMode {
+    /// Parses a mode name, reporting the supported names on failure.
+    fn from_lit(lit: &syn::LitStr) -> syn::Result<Self> {
+        parse_name(lit, DISPLAY_MODES, "display mode", "modes")
+    }
+}
+
+impl ParsePolicy {
+    /// Normalizes a variant value the way `e_macros::ParsePolicy::matches` compares it.
+    pub(crate) fn normalize(&self, value: &str) -> String {
+        let value = if self.trim { value.trim() } else { value };
+        let kept = value
+            .chars()
+            .filter(|c| !(self.ignore_separators && (*c == '-' || *c == '_')));
+        if self.case_insensitive {
+            kept.flat_map(char::to_lowercase).collect()
+        } else {
+            kept.collect()
+        }
+    }
+}
+
+impl ParsePolicy {
+    /// Builds the `ParsePolicy` constant of the runtime at `krate`.
+    pub(crate) fn to_tokens(self, krate: &syn::Path) -> TokenStream2 {
+        let ParsePolicy {
+            case_insensitive,
+            trim,
+            ignore_separators,
+        } = self;
+        quote! {
+            #krate::ParsePolicy {
+                case_insensitive: #case_insensitive,
+                trim: #trim,
+                ignore_separators: #ignore_separators,
+            }
+        }
+    }
+}
+
+/// Options parsed from the `#[e(...)]` attributes of a single variant.
+pub(crate) struct VariantAttrs {
+    /// The custom string value, from `#[e(value = "...")]`
+    pub(crate) value: Option<String>,
+    /// The custom index expression, from `#[e(index = ...)]`
+    pub(crate) index: Option<syn::Expr>,
+    /// Extra strings accepted when parsing, from repeated `#[e(alias = "...")]`, with the
+    /// tokens that errors about each of them point at
+    pub(crate) aliases: Vec<(String, TokenStream2)>,
+    /// Whether the variant is parsed with `Default` payloads, from `#[e(default_fields)]`
+    pub(crate) default_fields: bool,
+    /// Whether the variant captures unknown strings or indices, from `#[e(other)]`
+    pub(crate) other: bool,
+    /// Template for `Display`, from `#[e(display = "...")]`
+    pub(crate) display: Option<syn::LitStr>,
+    /// Template the variant is parsed from and formatted with, from `#[e(pattern = "...")]`
+    pub(crate) pattern: Option<syn::LitStr>,
+    /// Tokens that errors about the value of the variant point at
+    pub(crate) value_origin: TokenStream2,
+    /// Tokens that errors about the index of the variant point at
+    pub(crate) index_origin: TokenStream2,
+}
+
+impl VariantAttrs {
+    /// Attributes of a variant without any `#[e(...)]` key.
+    pub(crate) fn blank(variant: &syn::Variant) -> Self {
+        let ident = &variant.ident;
+        VariantAttrs {
+            value: None,
+            index: None,
+            aliases: Vec::new(),
+            default_fields: false,
+            other: false,
+            display: None,
+            pattern: None,
+            value_origin: quote! { #ident },
+            index_origin: quote! { #ident },
+        }
+    }
+
+    /// Parses the `#[e(...)]` attributes of `variant`.
+    ///
+    /// Unknown keys, repeated keys and values of the wrong kind are reported as errors.
+    pub(crate) fn parse(variant: &syn::Variant) -> syn::Result<Self> {
+        let mut attrs = VariantAttrs::blank(variant);
+
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("e"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("value") {
+                    ensure_unset(&meta, attrs.value.is_some())?;
+                    attrs.value = Some(parse_str_value(&meta)?);
+                    attrs.value_origin = attr.to_token_stream();
+                } else if meta.path.is_ident("index") {
+                    ensure_unset(&meta, attrs.index.is_some())?;
+                    attrs.index = Some(meta.value()?.parse()?);
+                    attrs.index_origin = attr.to_token_stream();
+                } else if meta.path.is_ident("alias") {
+                    let alias = parse_str_value(&meta)?;
+                    attrs.aliases.push((alias, attr.to_token_stream()));
+                } else if meta.path.is_ident("default_fields") {
+                    attrs.default_fields = true;
+                } else if meta.path.is_ident("other") {
+                    attrs.other = true;
+                } else if meta.path.is_ident("display") {
+                    ensure_unset(&meta, attrs.display.is_some())?;
+                    attrs.display = Some(parse_lit_str(&meta)?);
+                } else if meta.path.is_ident("pattern") {
+                    ensure_unset(&meta, attrs.pattern.is_some())?;
+                    attrs.pattern = Some(parse_lit_str(&meta)?);
+                } else {
+                    return Err(unknown_key(&meta, "e", VARIANT_KEYS));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Removes the `#[e(...)]` attributes from the enum, so it can be re-emitted.
+pub(crate) fn strip_attrs(attrs: &mut Vec<syn::Attribute>) {
+    attrs.retain(|attr| !attr.path().is_ident("e"));
+}
+
+/// Removes the `#[e(...)]` attributes from every variant, so the enum can be re-emitted.
+pub(crate) fn strip_variant_attrs(variants: &mut Punctuated<syn::Variant, syn::token::Comma>) {
+    for variant in variants.iter_mut() {
+        strip_attrs(&mut variant.attrs);
+    }
+}
+
+/// Parses `= "..."`, rejecting anything but a string literal.
+fn parse_str_value(meta: &ParseNestedMeta) -> syn::Result<String> {
+    parse_lit_str(meta).map(|lit| lit.value())
+}
+
+/// Parses `= "..."` into the literal itself, so errors about its contents can point at it.
+fn parse_lit_str(meta: &ParseNestedMeta) -> syn::Result<syn::LitStr> {
+    let expr: syn::Expr = meta.value()?.parse()?;
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => Ok(lit),
+        expr => Err(syn::Error::new_spanned(
+            expr,
+            format!(
+                "expected a string literal for `{}`, e.g. `{} = \"...\"`",
+                key_name(meta),
+                key_name(meta)
+            ),
+        )),
+    }
+}
+
+/// Looks up the name written in `lit` in `table`, reporting an unknown `kind` and the supported
+/// names, as `plural`, on failure.
+pub(crate) fn parse_name<T: Copy>(
+    lit: &syn::LitStr,
+    table: &[(&str, T)],
+    kind: &str,
+    plural: &str,
+) -> syn::Result<T> {
+    let name = lit.value();
+    table
+        .iter()
+        .find(|(entry, _)| *entry == name)
+        .map(|(_, value)| *value)
+        .ok_or_else(|| {
+            let supported = table
+                .iter()
+                .map(|(entry, _)| format!("\"{}\"", entry))
+                .collect::<Vec<_>>()
+                .join(", ");
+            syn::Error::new_spanned(
+                lit,
+                format!(
+                    "unknown {} {:?} (supported {}: {})",
+                    kind, name, plural, supported
+                ),
+            )
+        })
+}
+
+/// Reports a key that was given more than once.
+fn ensure_unset(meta: &ParseNestedMeta, is_set: bool) -> syn::Result<()> {
+    if is_set {
+        Err(meta.error(format!("duplicate `{}` key", key_name(meta))))
+    } else {
+        Ok(())
+    }
+}
+
+/// Builds the error for an unsupported key, suggesting the closest supported one.
+pub(crate) fn unknown_key(meta: &ParseNestedMeta, attr: &str, supported: &[&str]) -> syn::Error {
+    let key = key_name(meta);
+    let supported_list = supported
+        .iter()
+        .map(|k| format!("`{}`", k))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let hint = supported
+        .iter()
+        .map(|k| (edit_distance(&key, k), k))
+        .filter(|(distance, k)| *distance <= k.len() / 3 + 1)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, k)| format!(", did you mean `{}`?", k))
+        .unwrap_or_default();
+    syn::Error::new_spanned(
+        &meta.path,
+        format!(
+            "unknown `{}` attribute key `{}`{} (supported keys: {})",
+            attr, key, hint, supported_list
+        ),
+    )
+}
+
+fn key_name(meta: &ParseNestedMeta) -> String {
+    meta.path.to_token_stream().to_string().replace(' ', "")
+}
+
+/// Levenshtein distance between two keys.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev + usize::from(ca != *cb);
+            prev = row[j + 1];
+            row[j + 1] = substitute.min(prev + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
@@ -0,0 +1,104 @@
+/// Case conversion applied by `#[value(rename_all = "...")]` to derive values from variant names.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenameRule {
+    /// `lowercase`
+    Lower,
+    /// `UPPERCASE`
+    Upper,
+    /// `PascalCase`
+    Pascal,
+    /// `camelCase`
+    Camel,
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+    /// `SCREAMING-KEBAB-CASE`
+    ScreamingKebab,
+}
+
+const RULES: &[(&str, RenameRule)] = &[
+    ("lowercase", RenameRule::Lower),
+    ("UPPERCASE", RenameRule::Upper),
+    ("PascalCase", RenameRule::Pascal),
+    ("camelCase", RenameRule::Camel),
+    ("snake_case", RenameRule::Snake),
+    ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnake),
+    ("kebab-case", RenameRule::Kebab),
+    ("SCREAMING-KEBAB-CASE", RenameRule::ScreamingKebab),
+];
+
+impl RenameRule {
+    /// Parses a rule name, reporting the supported names on failure.
+    pub(crate) fn from_lit(lit: &syn::LitStr) -> syn::Result<Self> {
+        super::attr::parse_name(lit, RULES, "rename_all rule", "rules")
+    }
+
+    /// Applies the rule to a variant name.
+    pub(crate) fn apply(self, ident: &str) -> String {
+        let words = split_words(ident);
+        match self {
+            RenameRule::Lower => words.concat().to_lowercase(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            RenameRule::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| {
+                    if i == 0 {
+                        w.to_lowercase()
+                    } else {
+                        capitalize(w)
+                    }
+                })
+                .collect(),
+            RenameRule::Snake => join_lower(&words, "_"),
+            RenameRule::ScreamingSnake => join_lower(&words, "_").to_uppercase(),
+            RenameRule::Kebab => join_lower(&words, "-"),
+            RenameRule::ScreamingKebab => join_lower(&words, "-").to_uppercase(),
+        }
+    }
+}
+
+/// Splits an identifier into words at underscores and case changes, keeping acronyms together,
+/// so `HTTPStatus2xx` becomes `HTTP`, `Status2xx`.
+fn split_words(ident: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in ident.split('_').filter(|part| !part.is_empty()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let (at, c) = chars[i];
+            let prev = chars[i - 1].1;
+            let next_is_lower = chars.get(i + 1).is_some_and(|(_, n)| n.is_lowercase());
+            let boundary = c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase() && next_is_lower));
+            if boundary {
+                words.push(&part[start..at]);
+                start = at;
+            }
+        }
+        words.push(&part[start..]);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn join_lower(words: &[&str], separator: &str) -> String {
+    words
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
@@ -0,0 +1,1444 @@
+use super::attr::{ContainerAttrs, DisplayMode, VariantAttrs};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use std::collections::HashMap;
+use syn::{
+    ext::IdentExt, parse_quote, parse_quote_spanned, punctuated::Punctuated, spanned::Spanned,
+};
+
+/// Creates the structure for the enhanced enum.
+///
+/// This function generates the main implementation for the enum, including:
+/// - Attribute processing
+/// - Variant implementations
+/// - Display and Serde implementations
+///
+/// It backs the `#[value]` attribute, which re-emits the enum without its `#[e(...)]` attributes.
+pub(crate) fn create_structure(
+    args: TokenStream2,
+    enum_input: syn::ItemEnum,
+) -> syn::Result<TokenStream2> {
+    let enum_name = enum_input.ident;
+    let generics = enum_input.generics;
+    let vis = enum_input.vis;
+    let mut attrs = enum_input.attrs;
+    let mut variants = enum_input.variants;
+
+    // Container options come from the attribute arguments and from `#[e(...)]` on the enum
+    let mut options = ContainerAttrs::default();
+    options.parse_args(args)?;
+    options.parse_attrs(&attrs)?;
+    super::attr::strip_attrs(&mut attrs);
+    
+    // Split attributes into derive, repr, and other attributes
+    let (derive_attrs, repr_attrs, other_attrs) = split_attributes(attrs);
+    
+    // Process derive attributes to determine which traits are derived
+    let (debug, has_serialize, has_deserialize, derive_items) = process_derive_attrs(derive_attrs);
+
+    // Get representation type and new repr attributes
+    let (repr_ty, new_reprs) = super::repr_ty(repr_attrs, &variants)?;
+    
+    // `serde = "..."` generates the Serde impls, which would conflict with derived ones
+    if options.serde.is_some() {
+        let derived = derive_items
+            .iter()
+            .find(|path| path.is_ident("Serialize") || path.is_ident("Deserialize"));
+        if let Some(path) = derived {
+            return Err(syn::Error::new_spanned(
+                path,
+                "`serde = \"...\"` implements `Serialize` and `Deserialize`, remove them from the derive",
+            ));
+        }
+    }
+
+    // Without `display` or `no_display`, a derived Debug still implies Display, as a deprecated
+    // fallback that warns at the derive
+    let display_deprecation = match &debug {
+        Some(debug) if options.display.is_none() && !options.no_display => {
+            options.display = Some(DisplayMode::Value);
+            display_deprecation(debug)
+        }
+        _ => quote! {},
+    };
+
+    // Generate implementations for variants, Display, and Serde helpers if Serialize or
+    // Deserialize is derived or generated
+    options.to_serde = has_serialize || options.serde.is_some();
+    options.from_serde = has_deserialize || options.serde.is_some();
+    let enum_impls = enum_impls(&enum_name, &generics, &variants, &repr_ty, &options)?;
+    
+    super::attr::strip_variant_attrs(&mut variants);
+    let where_clause = &generics.where_clause;
+
+    // Combine all generated code into final implementation
+    Ok(quote! {
+        #[doc(hidden)]
+        #(#other_attrs)*
+        #new_reprs
+        #[derive(#(#derive_items),*)]
+        #vis enum #enum_name #generics #where_clause {
+            #variants
+        }
+        #enum_impls
+        #display_deprecation
+    })
+}
+
+/// Creates the implementations for an enum deriving `Value`.
+///
+/// The enum itself is left untouched, so only the implementations are emitted. Other derives are
+/// not visible to a derive macro, so there is no deprecated `Debug` fallback for `Display` and the
+/// Serde helpers are never generated.
+pub(crate) fn derive_structure(input: syn::DeriveInput) -> syn::Result<TokenStream2> {
+    let variants = match input.data {
+        syn::Data::Enum(data) => data.variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`Value` can only be derived for enums",
+            ))
+        }
+    };
+    let mut options = ContainerAttrs::default();
+    options.parse_attrs(&input.attrs)?;
+    let (_, repr_attrs, _) = split_attributes(input.attrs);
+    let (repr_ty, _) = super::repr_ty(repr_attrs, &variants)?;
+
+    enum_impls(&input.ident, &input.generics, &variants, &repr_ty, &options)
+}
+
+/// Generates every implementation for the enum, shared by the `#[value]` and `#[derive(Value)]`
+/// entry points.
+fn enum_impls(
+    enum_name: &syn::Ident,
+    generics: &syn::Generics,
+    variants: &Punctuated<syn::Variant, syn::token::Comma>,
+    repr_ty: &syn::Path,
+    options: &ContainerAttrs,
+) -> syn::Result<TokenStream2> {
+    // `index_type` decouples the type of indices from the memory layout of the enum
+    let repr_ty = options.index_type.as_ref().unwrap_or(repr_ty);
+    let (attrs, mut errors) = parse_variant_attrs(variants);
+    let parse_generics = parse_generics(generics, variants, &attrs, options);
+    let others = OtherVariants::find(variants, &attrs, repr_ty)?;
+    let variant_drives_impl = variant_drives_impl(
+        enum_name,
+        generics,
+        &parse_generics,
+        variants,
+        &attrs,
+        repr_ty,
+        options,
+        &others,
+    )
+    .unwrap_or_else(|error| {
+        push_error(&mut errors, error);
+        TokenStream2::new()
+    });
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+    let std_traits_impl = std_traits_impl(
+        enum_name,
+        generics,
+        &parse_generics,
+        repr_ty,
+        options,
+        &others,
+    );
+    let display_impl = generate_display_impl(enum_name, generics, variants, &attrs, options)?;
+    let serde_impl = serde_impl(
+        enum_name,
+        generics,
+        options.to_serde,
+        options.from_serde,
+        &options.runtime_path(),
+    );
+    let serde_impls = super::serde::serde_impls(
+        enum_name,
+        generics,
+        &parse_generics,
+        variants,
+        &attrs,
+        repr_ty,
+        options,
+        &others,
+    )?;
+
+    Ok(quote! {
+        #variant_drives_impl
+
+        #std_traits_impl
+
+        #display_impl
+
+        #serde_impl
+
+        #serde_impls
+    })
+}
+
+/// Generates implementations for enum variants.
+///
+/// This function creates:
+/// - e_macros::Value implementation exposing repr_ty as `Repr`
+/// - TryFrom<repr_ty> implementation, or From<repr_ty> with an `other` variant capturing indices
+/// - TryFrom<&str> implementation, or From<&str> with an `other` variant capturing strings
+/// - value(), index(), variant_count() and from_index() const fns, and iter()
+/// - VARIANT_COUNT, VARIANTS (unit-only enums), VALUES and INDICES associated consts
+///
+/// Duplicate string values and duplicate indices across variants are rejected.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn variant_drives_impl(
+    enum_name: &syn::Ident,
+    generics: &syn::Generics,
+    parse_generics: &syn::Generics,
+    variants: &Punctuated<syn::Variant, syn::token::Comma>,
+    attrs: &[VariantAttrs],
+    repr_ty: &syn::Path,
+    options: &ContainerAttrs,
+    others: &OtherVariants,
+) -> syn::Result<TokenStream2> {
+    let mut variant_derive_value_expr: Vec<syn::Arm> = Vec::new();
+    let mut variant_derive_index_expr: Vec<syn::Arm> = Vec::new();
+    let mut variant_derive_from_expr: Vec<syn::Arm> = Vec::new();
+    let mut variant_from_index_expr: Vec<syn::Arm> = Vec::new();
+    // Pairs of string value and the result of parsing it
+    let mut variant_derive_from_str_expr: Vec<(TokenStream2, TokenStream2)> = Vec::new();
+    // Parsers for `#[e(pattern = "...")]`, tried when no value matches
+    let mut pattern_checks: Vec<TokenStream2> = Vec::new();
+    let pattern_input = if options.parse.trim {
+        quote! { value.trim() }
+    } else {
+        quote! { value }
+    };
+    // Per-variant entries of the `VALUES` and `INDICES` tables
+    let mut variant_values: Vec<TokenStream2> = Vec::new();
+    let mut variant_indices: Vec<syn::Expr> = Vec::new();
+    // Every variant in declaration order, data variants built from `Default` payloads
+    let mut variant_defaults: Vec<TokenStream2> = Vec::new();
+    // Payload types that must implement `Default` for `iter()` to be available
+    let mut default_bounds: Vec<syn::Type> = Vec::new();
+    // Last explicit index, or discriminant when the enum has any, and how many implicit indices
+    // follow it. Before the first one, indices count from 1 while discriminants count from 0 like
+    // rustc does
+    let follows_discriminants = variants.iter().any(|v| v.discriminant.is_some());
+    let mut index_base: syn::Expr = parse_quote!(0);
+    let mut index_offset: i128 = if follows_discriminants { -1 } else { 0 };
+    // `#[e(index)]` and discriminant pairs that can only be compared by the compiler
+    let mut discriminant_checks: Vec<TokenStream2> = Vec::new();
+    // Implicit indices the compiler computes, one const per variant shared by every use
+    let mut index_consts: Vec<TokenStream2> = Vec::new();
+    // Every index with its folded value, whether rustc already compares it as a discriminant,
+    // the variant name and where the index comes from, to compare the indices that cannot be
+    // folded
+    let mut index_entries: Vec<(syn::Expr, Option<i128>, bool, String, proc_macro2::Span)> =
+        Vec::new();
+    let repr_range = super::repr_range(repr_ty);
+    let enum_name_str = enum_name.unraw().to_string();
+    let krate = options.runtime_path();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let parse_where_clause = &parse_generics.where_clause;
+    // Folded value of the last index, if it is built from integer literals only, counted like
+    // `index_offset`
+    let mut last_index_value: Option<i128> = Some(if follows_discriminants { -1 } else { 0 });
+    let mut seen_values: HashMap<String, (TokenStream2, String)> = HashMap::new();
+    let mut seen_indices: HashMap<i128, (TokenStream2, String)> = HashMap::new();
+    let mut errors: Option<syn::Error> = None;
+
+    // Process each variant
+    for (variant, attrs) in variants.iter().zip(attrs) {
+        // Extract custom attributes (e.g., value and index)
+        let VariantAttrs {
+            value,
+            index,
+            aliases,
+            default_fields,
+            pattern: value_pattern,
+            value_origin,
+            index_origin,
+            ..
+        } = attrs;
+        let mut index_origin = index_origin.clone();
+        let ident = &variant.ident;
+        let ident_str = ident.unraw().to_string();
+
+        let value_str = value.clone().unwrap_or_else(|| options.derive_value(ident));
+        check_duplicate(
+            &mut seen_values,
+            options.parse.normalize(&value_str),
+            value_origin.clone(),
+            format!("value {:?}", value_str),
+            &mut errors,
+        );
+        for (alias, alias_origin) in aliases {
+            check_duplicate(
+                &mut seen_values,
+                options.parse.normalize(alias),
+                alias_origin.clone(),
+                format!("alias {:?}", alias),
+                &mut errors,
+            );
+        }
+
+        // Generate value expression
+        let value_expr = quote! { #value_str };
+        variant_values.push(value_expr.clone());
+
+        // Pattern matching the variant, and the variant built with `Default` payloads
+        let (pattern, default_constructor) = match &variant.fields {
+            syn::Fields::Unit => (quote! { Self::#ident }, quote! { Self::#ident }),
+            syn::Fields::Named(fields) => {
+                let names = fields.named.iter().map(|field| &field.ident);
+                (
+                    quote! { Self::#ident { .. } },
+                    quote! { Self::#ident { #(#names: ::core::default::Default::default()),* } },
+                )
+            }
+            syn::Fields::Unnamed(fields) => {
+                let payload = fields
+                    .unnamed
+                    .iter()
+                    .map(|_| quote! { ::core::default::Default::default() });
+                (
+                    quote! { Self::#ident(..) },
+                    quote! { Self::#ident(#(#payload),*) },
+                )
+            }
+        };
+        variant_defaults.push(default_constructor.clone());
+        default_bounds.extend(variant.fields.iter().map(|field| field.ty.clone()));
+
+        // Result of parsing the variant from its value or its index, which is only possible for
+        // data variants whose payloads are filled in with `default_fields`. With an `other`
+        // variant the conversion is infallible, and inputs that cannot be converted are captured
+        // by it instead, so no arm is generated for them.
+        let constructible = variant.fields.is_empty() || options.default_fields || *default_fields;
+        let parse_result = |capturing: &Option<syn::Ident>, requires_data: TokenStream2| {
+            match capturing {
+                Some(other) if other == ident => None,
+                Some(_) if constructible => Some(default_constructor.clone()),
+                Some(_) => None,
+                None if constructible => {
+                    Some(quote! { ::core::result::Result::Ok(#default_constructor) })
+                }
+                None => Some(quote! {
+                    ::core::result::Result::Err(#krate::Error::#requires_data(#enum_name_str, #ident_str, value))
+                }),
+            }
+        };
+        let from_str_result = parse_result(&others.string, quote! { value_requires_data });
+        let from_index_result = parse_result(&others.index, quote! { requires_data });
+        if others.string.as_ref() == Some(ident) {
+            variant_derive_value_expr.push(parse_quote! {
+                Self::#ident(ref captured) => captured.as_str(),
+            });
+        } else {
+            variant_derive_value_expr.push(parse_quote! {
+                #pattern => #value_expr,
+            });
+        }
+        if let Some(value_pattern) = value_pattern {
+            let pattern =
+                super::template::parse_pattern(value_pattern, variant, &value_str, &options.parse);
+            match pattern {
+                Ok(pattern) => pattern_checks.push(pattern_check(
+                    &pattern,
+                    variant,
+                    &pattern_input,
+                    &enum_name_str,
+                    others.string.is_some(),
+                    &krate,
+                )),
+                Err(error) => {
+                    push_error(&mut errors, error);
+                    continue;
+                }
+            }
+        }
+        // Aliases parse to the same result as the value
+        if let Some(from_str_result) = from_str_result {
+            variant_derive_from_str_expr.push((value_expr, from_str_result.clone()));
+            for (alias, _) in aliases {
+                variant_derive_from_str_expr.push((quote! { #alias }, from_str_result.clone()));
+            }
+        }
+
+        // An explicit discriminant is the default index, and must agree with `#[e(index)]`
+        let index = match (index.clone(), &variant.discriminant) {
+            (Some(index), Some((_, discriminant))) if !options.allow_index_override => {
+                match (super::eval_index(&index), super::eval_index(discriminant)) {
+                    (Some(index_value), Some(discriminant_value)) => {
+                        if index_value != discriminant_value {
+                            push_error(
+                                &mut errors,
+                                syn::Error::new_spanned(
+                                    &index_origin,
+                                    format!(
+                                        "index {} contradicts the discriminant {} of variant `{}`; \
+                                         remove the index or add `allow_index_override` to the enum options",
+                                        index_value, discriminant_value, ident
+                                    ),
+                                ),
+                            );
+                            continue;
+                        }
+                    }
+                    // Expressions only the compiler can evaluate are compared in a const
+                    _ => discriminant_checks.push(discriminant_check(&index, discriminant, ident)),
+                }
+                Some(index)
+            }
+            (None, Some((_, discriminant))) => {
+                index_origin = quote! { #discriminant };
+                Some(discriminant.clone())
+            }
+            (index, _) => index,
+        };
+
+        // Implicit indices continue from the previous discriminant when the enum has any, as
+        // rustc counts discriminants, and from the previous index otherwise
+        let anchor = if follows_discriminants {
+            variant
+                .discriminant
+                .as_ref()
+                .map(|(_, discriminant)| discriminant.clone())
+        } else {
+            index.clone()
+        };
+        if let Some(anchor) = anchor {
+            last_index_value = super::eval_index(&anchor);
+            index_base = anchor;
+            index_offset = 0;
+        } else {
+            last_index_value = last_index_value.and_then(|v| v.checked_add(1));
+            index_offset += 1;
+        }
+
+        // Generate index expression: implicit indices are folded into literals when possible
+        let (idx, index_value) = if let Some(idx) = index {
+            let index_value = super::eval_index(&idx);
+            if let Some(Err(message)) =
+                index_value.map(|value| super::check_index_range(value, repr_ty))
+            {
+                push_error(&mut errors, syn::Error::new_spanned(&index_origin, message));
+                continue;
+            }
+            (idx, index_value)
+        } else {
+            let idx = match (last_index_value, &repr_range) {
+                (Some(index_value), Some((repr_name, _, max))) => {
+                    if index_value > *max {
+                        push_error(
+                            &mut errors,
+                            syn::Error::new_spanned(
+                                ident,
+                                format!(
+                                    "implicit index {} of variant `{}` overflows `{}` (maximum {})",
+                                    index_value, ident, repr_name, max
+                                ),
+                            ),
+                        );
+                        continue;
+                    }
+                    syn::parse_str(&format!("{}{}", index_value, repr_name))?
+                }
+                _ => {
+                    let message = format!(
+                        "implicit index of variant `{}` overflows `{}`",
+                        ident,
+                        quote!(#repr_ty).to_string().replace(' ', "")
+                    );
+                    let index_offset = proc_macro2::Literal::i128_unsuffixed(index_offset);
+                    let index_const = quote::format_ident!(
+                        "__E_MACROS_INDEX_{}_{}",
+                        enum_name_str,
+                        ident_str,
+                        span = ident.span()
+                    );
+                    index_consts.push(quote::quote_spanned! { ident.span() =>
+                        #[allow(non_upper_case_globals)]
+                        const #index_const: #repr_ty = match #repr_ty::checked_add(#index_base, #index_offset) {
+                            ::core::option::Option::Some(index) => index,
+                            ::core::option::Option::None => ::core::panic!(#message),
+                        };
+                    });
+                    parse_quote!(#index_const)
+                }
+            };
+            (idx, last_index_value)
+        };
+        let is_discriminant =
+            follows_discriminants && (attrs.index.is_none() || !options.allow_index_override);
+        index_entries.push((
+            idx.clone(),
+            index_value,
+            is_discriminant,
+            ident_str.clone(),
+            index_origin.span(),
+        ));
+        if let Some(index_value) = index_value {
+            check_duplicate(
+                &mut seen_indices,
+                index_value,
+                index_origin,
+                format!("index {}", index_value),
+                &mut errors,
+            );
+        }
+
+        variant_indices.push(idx.clone());
+
+        // Generate match arms for index and from implementations
+        if let syn::Fields::Unit = variant.fields {
+            variant_from_index_expr.push(parse_quote! {
+                index if index == #idx => ::core::option::Option::Some(Self::#ident),
+            });
+        }
+        if others.index.as_ref() == Some(ident) {
+            variant_derive_index_expr.push(parse_quote! {
+                Self::#ident(captured) => captured,
+            });
+        } else {
+            variant_derive_index_expr.push(parse_quote! {
+                #pattern => #idx,
+            });
+        }
+        if let Some(from_index_result) = from_index_result {
+            variant_derive_from_expr.push(parse_quote! {
+                value if value == #idx => #from_index_result,
+            });
+        }
+    }
+
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    // Indices that are not both folded, nor both discriminants rustc compares, are compared by
+    // the compiler, each against the indices before it
+    let mut duplicate_checks: Vec<TokenStream2> = Vec::new();
+    for (i, (idx, index_value, is_discriminant, ident_str, span)) in
+        index_entries.iter().enumerate()
+    {
+        let asserts: Vec<TokenStream2> = index_entries[..i]
+            .iter()
+            .filter(|(_, first_value, first_is_discriminant, _, _)| {
+                (index_value.is_none() || first_value.is_none())
+                    && !(*is_discriminant && *first_is_discriminant)
+            })
+            .map(|(first_idx, _, _, first_ident_str, _)| {
+                let message = format!(
+                    "the index of variant `{}` duplicates the index of variant `{}`",
+                    ident_str, first_ident_str
+                );
+                quote::quote_spanned! { *span => ::core::assert!(index != #first_idx, #message); }
+            })
+            .collect();
+        if !asserts.is_empty() {
+            duplicate_checks.push(quote::quote_spanned! { *span =>
+                const _: () = {
+                    let index: #repr_ty = #idx;
+                    #(#asserts)*
+                };
+            });
+        }
+    }
+
+    let variant_count = variants.len();
+
+    // `VARIANTS` only exists when every variant can be written without a payload
+    let variants_const = if default_bounds.is_empty() {
+        quote! {
+            /// Every variant, in declaration order.
+            /// # Example
+            /// ```rust
+            /// #[e_macros::value(display)]
+            /// #[derive(Debug, PartialEq)]
+            /// enum Color {
+            ///     Red,
+            ///     Green,
+            /// }
+            /// fn main() {
+            ///     assert_eq!(Color::VARIANTS, [Color::Red, Color::Green]);
+            /// }
+            /// ```
+            pub const VARIANTS: [Self; #variant_count] = [#(#variant_defaults),*];
+        }
+    } else {
+        quote! {}
+    };
+    // Bounds on the payload types are written as higher-ranked bounds so that they are only
+    // checked where `iter()` is called, instead of rejecting the whole impl block
+    let iter_body = if default_bounds.is_empty() {
+        quote! { ::core::iter::IntoIterator::into_iter(Self::VARIANTS) }
+    } else {
+        quote! { ::core::iter::IntoIterator::into_iter([#(#variant_defaults),*]) }
+    };
+    let iter_where = if default_bounds.is_empty() {
+        quote! {}
+    } else {
+        quote! { where #(for<'__e> #default_bounds: ::core::default::Default),* }
+    };
+
+    // Generate TryFrom<repr_ty> implementation, or From<repr_ty> when unknown indices are
+    // captured, which also provides an infallible TryFrom through the blanket impl
+    let from_impl = match &others.index {
+        Some(other) => quote! {
+            impl #impl_generics ::core::convert::From<#repr_ty> for #enum_name #ty_generics #parse_where_clause {
+                fn from(value: #repr_ty) -> Self {
+                    match value {
+                        #(#variant_derive_from_expr)*
+                        _ => Self::#other(value),
+                    }
+                }
+            }
+        },
+        None => quote! {
+            impl #impl_generics ::core::convert::TryFrom<#repr_ty> for #enum_name #ty_generics #parse_where_clause {
+                type Error = #krate::Error;
+
+                fn try_from(value: #repr_ty) -> ::core::result::Result<Self, #krate::Error> {
+                    match value {
+                        #(#variant_derive_from_expr)*
+                        _ => ::core::result::Result::Err(#krate::Error::unknown_index(#enum_name_str, value)),
+                    }
+                }
+            }
+        },
+    };
+    let from_index_fallback = match &others.index {
+        Some(other) => quote! { ::core::option::Option::Some(Self::#other(index)) },
+        None => quote! { ::core::option::Option::None },
+    };
+
+    // Generate TryFrom<&str> implementation, matching exactly unless the parse policy
+    // needs more than trimming
+    let policy = &options.parse;
+    let unknown_value = match &others.string {
+        Some(other) => quote! { Self::#other(::core::convert::Into::into(value)) },
+        None => quote! {
+            ::core::result::Result::Err(#krate::Error::unknown_value(#enum_name_str, value))
+        },
+    };
+    let from_str_body = if policy.case_insensitive || policy.ignore_separators {
+        let checks = variant_derive_from_str_expr
+            .iter()
+            .map(|(value_expr, result)| {
+                quote! {
+                    if Self::PARSE_POLICY.matches(value, #value_expr) {
+                        return #result;
+                    }
+                }
+            });
+        quote! {
+            #(#checks)*
+            #(#pattern_checks)*
+            #unknown_value
+        }
+    } else {
+        let input = if policy.trim {
+            quote! { value.trim() }
+        } else {
+            quote! { value }
+        };
+        let arms = variant_derive_from_str_expr
+            .iter()
+            .map(|(value_expr, result)| quote! { #value_expr => #result, });
+        quote! {
+            match #input {
+                #(#arms)*
+                _ => {
+                    #(#pattern_checks)*
+                    #unknown_value
+                }
+            }
+        }
+    };
+    let from_str_impl = if others.string.is_some() {
+        quote! {
+            impl #impl_generics ::core::convert::From<&str> for #enum_name #ty_generics #parse_where_clause {
+                fn from(value: &str) -> Self {
+                    #from_str_body
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics ::core::convert::TryFrom<&str> for #enum_name #ty_generics #parse_where_clause {
+                type Error = #krate::Error;
+
+                fn try_from(value: &str) -> ::core::result::Result<Self, #krate::Error> {
+                    #from_str_body
+                }
+            }
+        }
+    };
+    // A captured string is borrowed from the enum, which rules out `const fn`
+    let value_signature = if others.string.is_some() {
+        quote! { pub fn value(&self) -> &str }
+    } else {
+        quote! { pub const fn value(&self) -> &'static str }
+    };
+
+    let policy = policy.to_tokens(&krate);
+
+    // Combine all implementations
+    Ok(quote! {
+        #(#discriminant_checks)*
+
+        #(#index_consts)*
+
+        #(#duplicate_checks)*
+
+        impl #impl_generics #krate::Value for #enum_name #ty_generics #where_clause {
+            type Repr = #repr_ty;
+        }
+
+        #from_impl
+
+        #from_str_impl
+
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// How `TryFrom<&str>` matches input strings against variant values.
+            pub const PARSE_POLICY: #krate::ParsePolicy = #policy;
+
+            /// # Returns the string value of the enum variant.
+            /// # Example
+            /// ```rust
+            /// #[e_macros::value(display)]
+            /// #[derive(Debug, PartialEq)]
+            /// enum Color {
+            ///     #[e(value = "RED", index = 0)]
+            ///     Red,
+            ///     #[e(value = "GREEN", index = 1)]
+            ///     Green,
+            ///     #[e(value = "BLUE", index = 2)]
+            ///     Blue,
+            /// }
+            /// fn main() {
+            ///     let color = Color::Green;
+            ///     println!("Color value: {:?}", color);
+            /// }
+            /// ```
+            #value_signature {
+                match *self {
+                    #(#variant_derive_value_expr)*
+                }
+            }
+
+            /// #Returns the index value of the enum variant.
+            /// # Example
+            /// ```rust
+            /// #[e_macros::value(display)]
+            /// #[derive(Debug, PartialEq)]
+            /// enum Color {
+            ///     #[e(value = "RED", index = 0)]
+            ///     Red,
+            ///     #[e(value = "GREEN", index = 1)]
+            ///     Green,
+            ///     #[e(value = "BLUE", index = 2)]
+            ///     Blue,
+            /// }
+            /// fn main() {
+            ///     let color = Color::Green;
+            ///     println!("Color value: {:?}", color);
+            /// }
+            /// ```
+            pub const fn index(&self) -> #repr_ty {
+                match *self {
+                    #(#variant_derive_index_expr)*
+                }
+            }
+
+            /// Returns the unit variant with the given index, or `None` if no variant has it or
+            /// the variant carries data.
+            /// # Example
+            /// ```rust
+            /// #[e_macros::value(display)]
+            /// #[derive(Debug, PartialEq)]
+            /// enum Color {
+            ///     #[e(index = 4)]
+            ///     Red,
+            ///     Green,
+            /// }
+            /// const GREEN: Option<Color> = Color::from_index(5);
+            /// fn main() {
+            ///     assert_eq!(GREEN, Some(Color::Green));
+            ///     assert_eq!(Color::from_index(0), None);
+            /// }
+            /// ```
+            pub const fn from_index(index: #repr_ty) -> ::core::option::Option<Self> {
+                match index {
+                    #(#variant_from_index_expr)*
+                    _ => #from_index_fallback,
+                }
+            }
+
+            /// #Returns the number of variants in the enum.
+            /// # Example
+            /// ```rust
+            /// 
+            /// #[e_macros::value]
+            /// enum Color {
+            ///     Red,
+            ///     Green,
+            ///     Blue,
+            /// }
+            /// fn main() {
+            ///     println!("Cariant len: {}", Color::variant_count());
+            /// }
+            /// ```
+            pub const fn variant_count() -> usize {
+                Self::VARIANT_COUNT
+            }
+
+            /// The number of variants in the enum.
+            pub const VARIANT_COUNT: usize = #variant_count;
+
+            #variants_const
+
+            /// The string value of every variant, in declaration order.
+            pub const VALUES: [&'static str; #variant_count] = [#(#variant_values),*];
+
+            /// The index of every variant, in declaration order.
+            pub const INDICES: [#repr_ty; #variant_count] = [#(#variant_indices),*];
+
+            /// Iterates over every variant in declaration order.
+            ///
+            /// Variants carrying data are built with `Default` payloads, so for such enums this
+            /// is only available when every payload type implements `Default`.
+            /// # Example
+            /// ```rust
+            /// #[e_macros::value(display)]
+            /// #[derive(Debug, PartialEq)]
+            /// enum Shape {
+            ///     Empty,
+            ///     Circle(u32),
+            ///     Rect { w: u32, h: u32 },
+            /// }
+            /// fn main() {
+            ///     let shapes: Vec<Shape> = Shape::iter().collect();
+            ///     assert_eq!(shapes[2], Shape::Rect { w: 0, h: 0 });
+            /// }
+            /// ```
+            pub fn iter() -> impl ::core::iter::DoubleEndedIterator<Item = Self> + ::core::iter::ExactSizeIterator #iter_where {
+                #iter_body
+            }
+        }
+    })
+}
+
+/// Asserts at compile time that an `#[e(index)]` equals the variant's discriminant, for
+/// expressions that cannot be folded by the macro.
+fn discriminant_check(
+    index: &syn::Expr,
+    discriminant: &syn::Expr,
+    ident: &syn::Ident,
+) -> TokenStream2 {
+    let message = format!(
+        "the index of variant `{}` contradicts its discriminant; remove the index or add \
+         `allow_index_override` to the enum options",
+        ident
+    );
+    quote::quote_spanned! { index.span() =>
+        #[allow(clippy::unnecessary_cast)]
+        const _: () = ::core::assert!((#index) as i128 == (#discriminant) as i128, #message);
+    }
+}
+
+/// Generates the parser for a variant's `#[e(pattern = "...")]`, which returns the variant when
+/// `input` matches the pattern.
+///
+/// Fields are parsed with their `FromStr` impl. A field that fails to parse is reported as
+/// `ErrorKind::InvalidField`, or leaves the input to the string capturing `other` variant.
+fn pattern_check(
+    pattern: &super::template::Pattern,
+    variant: &syn::Variant,
+    input: &TokenStream2,
+    enum_name_str: &str,
+    capturing: bool,
+    krate: &syn::Path,
+) -> TokenStream2 {
+    let ident = &variant.ident;
+    let ident_str = ident.unraw().to_string();
+    let literals = &pattern.literals;
+    let suffix = &pattern.suffix;
+    let parts: Vec<syn::Ident> = (0..pattern.fields.len())
+        .map(|i| quote::format_ident!("__part{}", i))
+        .collect();
+    let members = &pattern.fields;
+    let bindings: Vec<syn::Ident> = members
+        .iter()
+        .map(super::template::Placeholder::binding)
+        .collect();
+    let parsed = members.iter().zip(&parts).map(|(member, part)| {
+        let ty = variant
+            .fields
+            .iter()
+            .enumerate()
+            .find(|(i, field)| match (member, &field.ident) {
+                (syn::Member::Named(name), Some(ident)) => name == ident,
+                (syn::Member::Unnamed(index), None) => index.index as usize == *i,
+                _ => false,
+            })
+            .map(|(_, field)| &field.ty);
+        quote! { <#ty as ::core::str::FromStr>::from_str(#part) }
+    });
+    let constructor = quote! { Self::#ident { #(#members: #bindings),* } };
+
+    let body = if members.is_empty() {
+        if capturing {
+            quote! { return #constructor; }
+        } else {
+            quote! { return ::core::result::Result::Ok(#constructor); }
+        }
+    } else if capturing {
+        quote! {
+            if let (#(::core::result::Result::Ok(#bindings),)*) = (#(#parsed,)*) {
+                return #constructor;
+            }
+        }
+    } else {
+        let field_names = members.iter().map(|member| match member {
+            syn::Member::Named(ident) => ident.unraw().to_string(),
+            syn::Member::Unnamed(index) => index.index.to_string(),
+        });
+        quote! {
+            #(
+                let #bindings = match #parsed {
+                    ::core::result::Result::Ok(field) => field,
+                    ::core::result::Result::Err(error) => {
+                        return ::core::result::Result::Err(#krate::Error::invalid_field(
+                            #enum_name_str,
+                            #ident_str,
+                            #field_names,
+                            value,
+                            error,
+                        ))
+                    }
+                };
+            )*
+            return ::core::result::Result::Ok(#constructor);
+        }
+    };
+    quote! {
+        if let ::core::option::Option::Some([#(#parts),*]) =
+            #krate::__private::match_pattern(#input, [#(#literals),*], #suffix, &Self::PARSE_POLICY)
+        {
+            #body
+        }
+    }
+}
+
+/// Records `key` as used by a variant, reporting an error on both variants if it was already used.
+fn check_duplicate<K: std::hash::Hash + Eq>(
+    seen: &mut HashMap<K, (TokenStream2, String)>,
+    key: K,
+    origin: TokenStream2,
+    label: String,
+    errors: &mut Option<syn::Error>,
+) {
+    if let Some((first, first_label)) = seen.get(&key) {
+        let mut error = syn::Error::new_spanned(&origin, format!("duplicate {}", label));
+        error.combine(syn::Error::new_spanned(
+            first,
+            format!("{} first used here", first_label),
+        ));
+        push_error(errors, error);
+    } else {
+        seen.insert(key, (origin, label));
+    }
+}
+
+/// Adds `error` to the errors reported for the enum.
+fn push_error(errors: &mut Option<syn::Error>, error: syn::Error) {
+    match errors {
+        Some(errors) => errors.combine(error),
+        None => *errors = Some(error),
+    }
+}
+
+/// Parses the `#[e(...)]` attributes of every variant.
+///
+/// Malformed attributes are returned as errors alongside blank attributes for their variant, so
+/// the other variants are still checked and all problems are reported together.
+fn parse_variant_attrs(
+    variants: &Punctuated<syn::Variant, syn::token::Comma>,
+) -> (Vec<VariantAttrs>, Option<syn::Error>) {
+    let mut errors: Option<syn::Error> = None;
+    let attrs = variants
+        .iter()
+        .map(|variant| {
+            VariantAttrs::parse(variant).unwrap_or_else(|error| {
+                push_error(&mut errors, error);
+                VariantAttrs::blank(variant)
+            })
+        })
+        .collect();
+    (attrs, errors)
+}
+
+/// Catch-all variants marked `#[e(other)]`, which make string or index conversions infallible.
+#[derive(Default)]
+pub(crate) struct OtherVariants {
+    /// Variant of shape `Other(String)`, capturing unknown strings
+    pub(crate) string: Option<syn::Ident>,
+    /// Variant of shape `Other(repr_ty)`, capturing unknown indices
+    pub(crate) index: Option<syn::Ident>,
+}
+
+impl OtherVariants {
+    /// Finds the `other` variants, checking their shape and that each kind is captured once.
+    fn find(
+        variants: &Punctuated<syn::Variant, syn::token::Comma>,
+        attrs: &[VariantAttrs],
+        repr_ty: &syn::Path,
+    ) -> syn::Result<Self> {
+        let mut others = OtherVariants::default();
+        let repr_name = repr_ty.segments.last().map(|segment| &segment.ident);
+        for (variant, attrs) in variants.iter().zip(attrs) {
+            if !attrs.other {
+                continue;
+            }
+            let field_ty = match &variant.fields {
+                syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    Some(&fields.unnamed[0].ty)
+                }
+                _ => None,
+            };
+            let last_segment = match field_ty {
+                Some(syn::Type::Path(ty)) if ty.qself.is_none() => ty.path.segments.last(),
+                _ => None,
+            };
+            let (slot, kind) = match last_segment {
+                Some(segment) if segment.ident == "String" && segment.arguments.is_empty() => {
+                    (&mut others.string, "strings")
+                }
+                Some(segment) if Some(&segment.ident) == repr_name => {
+                    (&mut others.index, "indices")
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        format!(
+                            "an `other` variant must have a single `String` field to capture \
+                             unknown strings, or a single `{}` field to capture unknown indices",
+                            quote!(#repr_ty).to_string().replace(' ', "")
+                        ),
+                    ))
+                }
+            };
+            if let Some(previous) = slot.replace(variant.ident.clone()) {
+                let mut error = syn::Error::new_spanned(
+                    &variant.ident,
+                    format!("only one `other` variant can capture unknown {}", kind),
+                );
+                error.combine(syn::Error::new_spanned(previous, "first used here"));
+                return Err(error);
+            }
+        }
+        Ok(others)
+    }
+}
+
+/// Adds a `Default` bound on the payload types of the variants parsed with `default_fields`, and
+/// `FromStr` bounds on those of variants with a pattern, to `generics`, for the impls that
+/// construct variants from strings and indices.
+fn parse_generics(
+    generics: &syn::Generics,
+    variants: &Punctuated<syn::Variant, syn::token::Comma>,
+    attrs: &[VariantAttrs],
+    options: &ContainerAttrs,
+) -> syn::Generics {
+    let mut generics = generics.clone();
+    for (variant, attrs) in variants.iter().zip(attrs) {
+        let predicates = &mut generics.make_where_clause().predicates;
+        for field in &variant.fields {
+            let ty = &field.ty;
+            if options.default_fields || attrs.default_fields {
+                predicates
+                    .push(parse_quote_spanned! { ty.span() => #ty: ::core::default::Default });
+            }
+            if attrs.pattern.is_some() {
+                predicates.push(parse_quote_spanned! { ty.span() =>
+                    #ty: ::core::str::FromStr
+                });
+                predicates.push(parse_quote_spanned! { ty.span() =>
+                    <#ty as ::core::str::FromStr>::Err: ::core::fmt::Display
+                });
+            }
+        }
+    }
+    generics
+}
+
+/// Generates the standard conversion and comparison traits built on `value()`, `index()` and
+/// `TryFrom<&str>`, except those opted out of with the `no_*` container options.
+fn std_traits_impl(
+    enum_name: &syn::Ident,
+    generics: &syn::Generics,
+    parse_generics: &syn::Generics,
+    repr_ty: &syn::Path,
+    options: &ContainerAttrs,
+    others: &OtherVariants,
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let parse_where_clause = &parse_generics.where_clause;
+    let skip = &options.skip;
+    let krate = options.runtime_path();
+    let mut impls = TokenStream2::new();
+
+    if !skip.from_str && others.string.is_some() {
+        impls.extend(quote! {
+            impl #impl_generics ::core::str::FromStr for #enum_name #ty_generics #parse_where_clause {
+                type Err = ::core::convert::Infallible;
+
+                fn from_str(value: &str) -> ::core::result::Result<Self, ::core::convert::Infallible> {
+                    ::core::result::Result::Ok(<Self as ::core::convert::From<&str>>::from(value))
+                }
+            }
+        });
+    } else if !skip.from_str {
+        impls.extend(quote! {
+            impl #impl_generics ::core::str::FromStr for #enum_name #ty_generics #parse_where_clause {
+                type Err = #krate::Error;
+
+                fn from_str(value: &str) -> ::core::result::Result<Self, #krate::Error> {
+                    <Self as ::core::convert::TryFrom<&str>>::try_from(value)
+                }
+            }
+        });
+    }
+    if !skip.as_ref {
+        impls.extend(quote! {
+            impl #impl_generics ::core::convert::AsRef<str> for #enum_name #ty_generics #where_clause {
+                fn as_ref(&self) -> &str {
+                    self.value()
+                }
+            }
+        });
+    }
+    if !skip.into_repr {
+        impls.extend(quote! {
+            impl #impl_generics ::core::convert::From<#enum_name #ty_generics> for #repr_ty #where_clause {
+                fn from(value: #enum_name #ty_generics) -> Self {
+                    value.index()
+                }
+            }
+        });
+    }
+    if !skip.into_str && others.string.is_some() {
+        // A captured string only lives as long as the enum
+        let mut generics = generics.clone();
+        generics.params.insert(0, parse_quote!('__e));
+        let (impl_generics, _, _) = generics.split_for_impl();
+        impls.extend(quote! {
+            impl #impl_generics ::core::convert::From<&'__e #enum_name #ty_generics> for &'__e str #where_clause {
+                fn from(value: &'__e #enum_name #ty_generics) -> Self {
+                    value.value()
+                }
+            }
+        });
+    } else if !skip.into_str {
+        impls.extend(quote! {
+            impl #impl_generics ::core::convert::From<&#enum_name #ty_generics> for &'static str #where_clause {
+                fn from(value: &#enum_name #ty_generics) -> Self {
+                    value.value()
+                }
+            }
+        });
+    }
+    if !skip.eq_str {
+        impls.extend(quote! {
+            impl #impl_generics ::core::cmp::PartialEq<&str> for #enum_name #ty_generics #where_clause {
+                fn eq(&self, other: &&str) -> bool {
+                    self.value() == *other
+                }
+            }
+        });
+    }
+    if !skip.eq_repr {
+        impls.extend(quote! {
+            impl #impl_generics ::core::cmp::PartialEq<#repr_ty> for #enum_name #ty_generics #where_clause {
+                fn eq(&self, other: &#repr_ty) -> bool {
+                    self.index() == *other
+                }
+            }
+        });
+    }
+
+    impls
+}
+
+/// Generates the serde_json helpers if Serialize or Deserialize is derived.
+///
+/// The helpers go through the `serde_json` re-exported by e-macros, so they are only emitted
+/// with its `serde` feature, and users do not need their own `serde_json` dependency.
+///
+/// For generic enums the helpers are bounded on `Self` implementing the derived trait, since
+/// the derives add bounds on the type parameters that the inherent impl does not repeat.
+#[cfg(feature = "serde")]
+fn serde_impl(
+    enum_name: &syn::Ident,
+    generics: &syn::Generics,
+    has_serialize: bool,
+    has_deserialize: bool,
+    krate: &syn::Path,
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let is_generic = !generics.params.is_empty();
+    let serialize_bound = if is_generic {
+        quote! { where Self: ::serde::Serialize }
+    } else {
+        quote! {}
+    };
+    let deserialize_bound = if is_generic {
+        quote! { where Self: ::serde::de::DeserializeOwned }
+    } else {
+        quote! {}
+    };
+
+    let serialize_impl = if has_serialize {
+        quote! {
+            /// Serializes the enum to a JSON string.
+            pub fn to_serde(
+                &self,
+            ) -> ::core::result::Result<#krate::__private::String, #krate::__private::serde_json::Error> #serialize_bound {
+                #krate::__private::serde_json::to_string(&self)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let deserialize_impl = if has_deserialize {
+        quote! {
+            /// Deserializes the enum from a JSON value.
+            pub fn from_serde(
+                value: #krate::__private::serde_json::Value,
+            ) -> ::core::result::Result<Self, #krate::__private::serde_json::Error> #deserialize_bound {
+                #krate::__private::serde_json::from_value(value)
+            }
+
+            /// Deserializes the enum from a JSON string.
+            pub fn from_serde_str(
+                json: &str,
+            ) -> ::core::result::Result<Self, #krate::__private::serde_json::Error> #deserialize_bound {
+                #krate::__private::serde_json::from_str(json)
+            }
+
+            /// Deserializes the enum from a reader of JSON.
+            pub fn from_serde_reader<__R: #krate::__private::Read>(
+                reader: __R,
+            ) -> ::core::result::Result<Self, #krate::__private::serde_json::Error> #deserialize_bound {
+                #krate::__private::serde_json::from_reader(reader)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            #serialize_impl
+            #deserialize_impl
+        }
+    }
+}
+
+/// Without the `serde` feature of e-macros there is no `serde_json` to generate helpers for.
+#[cfg(not(feature = "serde"))]
+fn serde_impl(
+    _enum_name: &syn::Ident,
+    _generics: &syn::Generics,
+    _has_serialize: bool,
+    _has_deserialize: bool,
+    _krate: &syn::Path,
+) -> TokenStream2 {
+    quote! {}
+}
+
+/// Splits attributes into derive, repr, and other attributes.
+fn split_attributes(
+    attrs: Vec<syn::Attribute>,
+) -> (
+    Vec<syn::Attribute>,
+    Vec<syn::Attribute>,
+    Vec<syn::Attribute>,
+) {
+    let mut derive_attrs = Vec::new();
+    let mut repr_attrs = Vec::new();
+    let mut other_attrs = Vec::new();
+
+    for attr in attrs {
+        if attr.path().is_ident("derive") {
+            derive_attrs.push(attr);
+        } else if attr.path().is_ident("repr") {
+            repr_attrs.push(attr);
+        } else {
+            other_attrs.push(attr);
+        }
+    }
+
+    (derive_attrs, repr_attrs, other_attrs)
+}
+
+/// Processes derive attributes to determine which traits are derived, returning the `Debug` path
+/// when it is.
+fn process_derive_attrs(
+    derive_attrs: Vec<syn::Attribute>,
+) -> (Option<syn::Path>, bool, bool, Vec<syn::Path>) {
+    let mut debug = None;
+    let mut has_serialize = false;
+    let mut has_deserialize = false;
+    let mut derive_items = Vec::new();
+
+    for attr in derive_attrs {
+        if let Ok(nested) = attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+        ) {
+            for path in nested {
+                if path.is_ident("Debug") {
+                    debug = Some(path.clone());
+                } else if path.is_ident("Serialize") {
+                    has_serialize = true;
+                } else if path.is_ident("Deserialize") {
+                    has_deserialize = true;
+                }
+                derive_items.push(path);
+            }
+        }
+    }
+
+    (debug, has_serialize, has_deserialize, derive_items)
+}
+
+/// Uses a deprecated item at the span of the `Debug` derive, so the Display inferred from it warns
+/// until the enum picks `display` or `no_display`.
+fn display_deprecation(debug: &syn::Path) -> TokenStream2 {
+    let marker = quote::format_ident!("__display_inferred_from_debug", span = debug.span());
+    quote! {
+        const _: () = {
+            #[deprecated(
+                note = "implementing `Display` because `Debug` is derived is deprecated, add `#[value(display)]` to keep it or `#[value(no_display)]` to drop it"
+            )]
+            #[allow(non_camel_case_types)]
+            struct __display_inferred_from_debug;
+            let _ = #marker;
+        };
+    }
+}
+
+/// Generates the Display implementation, when enabled or when a variant has a display template.
+///
+/// Variants without a template are formatted according to the display mode, padding values and
+/// names with `Formatter::pad` so width, fill and alignment apply to them.
+fn generate_display_impl(
+    enum_name: &syn::Ident,
+    generics: &syn::Generics,
+    variants: &Punctuated<syn::Variant, syn::token::Comma>,
+    attrs: &[VariantAttrs],
+    options: &ContainerAttrs,
+) -> syn::Result<TokenStream2> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut template_arms: Vec<TokenStream2> = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+    for (variant, attrs) in variants.iter().zip(attrs) {
+        if options.no_display {
+            if let Some(template) = &attrs.display {
+                push_error(
+                    &mut errors,
+                    syn::Error::new_spanned(
+                        template,
+                        "display templates are not used with `no_display`",
+                    ),
+                );
+            }
+            continue;
+        }
+        // A pattern formats the variant the way it is parsed, unless a display template is given
+        let Some(template) = attrs.display.as_ref().or(attrs.pattern.as_ref()) else {
+            continue;
+        };
+        let (format, placeholders) = match super::template::parse(template, variant) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                push_error(&mut errors, error);
+                continue;
+            }
+        };
+        let format = syn::LitStr::new(&format, template.span());
+        let ident = &variant.ident;
+        let bindings = variant.fields.iter().enumerate().map(|(i, field)| {
+            let member = match &field.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(i.into()),
+            };
+            let binding = super::template::Placeholder::binding(&member);
+            quote! { #member: #binding }
+        });
+        let args = placeholders.iter().enumerate().map(|(i, placeholder)| {
+            let arg = quote::format_ident!("__arg{}", i);
+            let expr = match placeholder {
+                super::template::Placeholder::Field(member) => {
+                    let binding = super::template::Placeholder::binding(member);
+                    quote! { #binding }
+                }
+                super::template::Placeholder::Value => quote! { self.value() },
+                super::template::Placeholder::Index => quote! { self.index() },
+            };
+            quote! { #arg = #expr }
+        });
+        template_arms.push(quote! {
+            Self::#ident { #(#bindings),* } => ::core::write!(__formatter, #format, #(#args),*),
+        });
+    }
+
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+    if options.no_display || (options.display.is_none() && template_arms.is_empty()) {
+        return Ok(quote! {});
+    }
+    let untemplated = match options.display.unwrap_or(DisplayMode::Value) {
+        DisplayMode::Value => quote! { __formatter.pad(self.value()) },
+        DisplayMode::Index => quote! { ::core::fmt::Display::fmt(&self.index(), __formatter) },
+        DisplayMode::Name => {
+            let names = variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let name = ident.unraw().to_string();
+                quote! { Self::#ident { .. } => #name, }
+            });
+            quote! {
+                __formatter.pad(match self {
+                    #(#names)*
+                })
+            }
+        }
+    };
+    let body = if template_arms.is_empty() {
+        untemplated
+    } else if template_arms.len() == variants.len() {
+        quote! {
+            match self {
+                #(#template_arms)*
+            }
+        }
+    } else {
+        quote! {
+            match self {
+                #(#template_arms)*
+                _ => #untemplated,
+            }
+        }
+    };
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Display for #enum_name #ty_generics #where_clause {
+            fn fmt(&self, __formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,165 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_quote, punctuated::Punctuated, Variant};
+
+mod attr;
+mod case;
+pub(crate) mod r#enum;
+mod serde;
+mod template;
+
+/// Primitive integer types accepted as `#[repr(...)]` and as `index_type`.
+pub(crate) const INT_TYPES: &[&str] = &[
+    "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "i128", "u128", "isize", "usize",
+];
+
+/// Determines the representation type for the enum based on attributes and variants.
+///
+/// Follows the Rust reference for enums: a primitive integer repr wins, also when combined with
+/// `C`, a lone `C` means `core::ffi::c_int`, and `align(N)`, `Rust` and `transparent` leave the
+/// discriminant type unspecified, which defaults to `i32`. Conflicting integer reprs and hints
+/// that are invalid on enums are reported as errors.
+pub(crate) fn repr_ty(
+    repr_attrs: Vec<syn::Attribute>,
+    variants: &Punctuated<Variant, syn::token::Comma>,
+) -> syn::Result<(syn::Path, TokenStream2)> {
+    let mut reprs: Vec<syn::Meta> = Vec::new();
+    for attr in &repr_attrs {
+        reprs.extend(
+            attr.parse_args_with(Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)?,
+        );
+    }
+
+    let mut int_repr: Option<&syn::Path> = None;
+    let mut c_repr: Option<&syn::Path> = None;
+    let mut transparent: Option<&syn::Path> = None;
+    for repr in &reprs {
+        let path = repr.path();
+        let name = path
+            .get_ident()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        match (name.as_str(), repr) {
+            ("C", syn::Meta::Path(_)) => c_repr = Some(path),
+            ("Rust", syn::Meta::Path(_)) | ("align", syn::Meta::List(_)) => {}
+            ("transparent", syn::Meta::Path(_)) => transparent = Some(path),
+            (int, syn::Meta::Path(_)) if INT_TYPES.contains(&int) => {
+                if let Some(previous) = int_repr.filter(|previous| *previous != path) {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        format!(
+                            "conflicting representation hints `{}` and `{}`",
+                            quote!(#previous),
+                            int
+                        ),
+                    ));
+                }
+                int_repr = Some(path);
+            }
+            ("packed", _) => {
+                return Err(syn::Error::new_spanned(
+                    repr,
+                    "`packed` is not a valid representation for enums",
+                ))
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    repr,
+                    format!(
+                        "unsupported enum representation `{}` (supported: `C`, `Rust`, \
+                         `transparent`, `align(N)` and primitive integer types)",
+                        quote!(#repr).to_string().replace(' ', "")
+                    ),
+                ))
+            }
+        }
+    }
+    if let Some(transparent) = transparent.filter(|_| int_repr.is_some() || c_repr.is_some()) {
+        return Err(syn::Error::new_spanned(
+            transparent,
+            "`transparent` cannot be combined with other representation hints",
+        ));
+    }
+
+    let repr_ty = match (int_repr, c_repr) {
+        (Some(int), _) => int.clone(),
+        (None, Some(_)) => parse_quote!(::core::ffi::c_int),
+        (None, None) => parse_quote!(i32),
+    };
+
+    let has_explicit_discriminants = variants.iter().any(|v| v.discriminant.is_some());
+    let repr_attr = if reprs.is_empty() && has_explicit_discriminants {
+        // Explicit enum values without a repr get the same `i32` the indices use
+        quote! { #[repr(i32)] }
+    } else {
+        quote! { #(#[repr(#reprs)])* }
+    };
+
+    Ok((repr_ty, repr_attr))
+}
+
+/// Evaluates an index expression made of an integer literal, optionally negated or parenthesized.
+///
+/// Returns `None` for any other expression, whose value is only known to the compiler.
+pub(crate) fn eval_index(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => eval_index(expr).and_then(i128::checked_neg),
+        syn::Expr::Paren(syn::ExprParen { expr, .. })
+        | syn::Expr::Group(syn::ExprGroup { expr, .. }) => eval_index(expr),
+        _ => None,
+    }
+}
+
+/// Returns the name and the inclusive value range of a fixed-width integer repr type.
+///
+/// `isize`, `usize` and any other type return `None`, as their range depends on the target.
+pub(crate) fn repr_range(repr_ty: &syn::Path) -> Option<(String, i128, i128)> {
+    let name = repr_ty.segments.last()?.ident.to_string();
+    let (min, max) = match name.as_str() {
+        "i8" => (i8::MIN.into(), i8::MAX.into()),
+        "u8" => (0, u8::MAX.into()),
+        "i16" => (i16::MIN.into(), i16::MAX.into()),
+        "u16" => (0, u16::MAX.into()),
+        "i32" => (i32::MIN.into(), i32::MAX.into()),
+        "u32" => (0, u32::MAX.into()),
+        "i64" => (i64::MIN.into(), i64::MAX.into()),
+        "u64" => (0, u64::MAX.into()),
+        "i128" => (i128::MIN, i128::MAX),
+        // Folded indices are `i128`, so larger `u128` values take the const evaluation path
+        "u128" => (0, i128::MAX),
+        _ => return None,
+    };
+    Some((name, min, max))
+}
+
+/// Checks a folded index against the range of the repr type, describing the range on failure.
+///
+/// Only the lower bound of `usize` is checked, as its upper bound depends on the target.
+pub(crate) fn check_index_range(value: i128, repr_ty: &syn::Path) -> Result<(), String> {
+    let (name, min, max) = match repr_range(repr_ty) {
+        Some((name, min, max)) => (name, min, Some(max)),
+        None if repr_ty.is_ident("usize") => ("usize".to_string(), 0, None),
+        None => return Ok(()),
+    };
+    if value >= min && max.map_or(true, |max| value <= max) {
+        return Ok(());
+    }
+    let max = match max {
+        // Report the real maximum of `u128` rather than the folding limit
+        _ if name == "u128" => u128::MAX.to_string(),
+        Some(max) => max.to_string(),
+        None => "usize::MAX".to_string(),
+    };
+    Err(format!(
+        "index {} is out of range for `{}` (valid range: {}..={})",
+        value, name, min, max
+    ))
+}
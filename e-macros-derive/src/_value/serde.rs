@@ -0,0 +1,651 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, parse_quote, parse_quote_spanned, punctuated::Punctuated, spanned::Spanned,
+};
+
+use super::attr::{ContainerAttrs, VariantAttrs};
+use super::r#enum::OtherVariants;
+
+/// How the impls generated by `#[value(serde = "...")]` represent unit variants.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SerdeMode {
+    /// `value`: the value string
+    Value,
+    /// `index`: the index
+    Index,
+    /// `lenient`: the value string, accepting values, aliases and indices
+    Lenient,
+}
+
+const MODES: &[(&str, SerdeMode)] = &[
+    ("value", SerdeMode::Value),
+    ("index", SerdeMode::Index),
+    ("lenient", SerdeMode::Lenient),
+];
+
+impl SerdeMode {
+    /// Parses a mode name, reporting the supported names on failure.
+    pub(crate) fn from_lit(lit: &syn::LitStr) -> syn::Result<Self> {
+        super::attr::parse_name(lit, MODES, "serde mode", "modes")
+    }
+
+    fn accepts_strings(self) -> bool {
+        self != SerdeMode::Index
+    }
+
+    fn accepts_indices(self) -> bool {
+        self != SerdeMode::Value
+    }
+}
+
+/// How a variant is serialized.
+enum Shape {
+    /// A unit variant, or the variant capturing unknown strings or indices, written as its value
+    /// or index
+    Unit,
+    /// A variant with fields, written externally tagged by its value string
+    Data,
+}
+
+/// A variant as seen by the generated serde impls.
+struct SerdeVariant<'a> {
+    variant: &'a syn::Variant,
+    shape: Shape,
+    /// The value string, which is also the tag of data variants
+    value: String,
+    /// Extra tags accepted in `lenient` mode
+    aliases: Vec<String>,
+}
+
+/// Generates `Serialize` and `Deserialize` impls that represent variants by their value strings
+/// or indices, for `#[value(serde = "...")]`.
+///
+/// Data variants are externally tagged by their value string, like the derived impls tag them by
+/// variant name. Deserialization goes through the generated `TryFrom` impls, so parse policies,
+/// aliases and `other` variants apply. Strings captured by an `other` variant have no index, so
+/// that variant is rejected in `index` mode.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn serde_impls(
+    enum_name: &syn::Ident,
+    generics: &syn::Generics,
+    parse_generics: &syn::Generics,
+    variants: &Punctuated<syn::Variant, syn::token::Comma>,
+    attrs: &[VariantAttrs],
+    repr_ty: &syn::Path,
+    options: &ContainerAttrs,
+    others: &OtherVariants,
+) -> syn::Result<TokenStream2> {
+    let Some(mode) = options.serde else {
+        return Ok(quote! {});
+    };
+    if let (SerdeMode::Index, Some(other)) = (mode, &others.string) {
+        return Err(syn::Error::new_spanned(
+            other,
+            format!(
+                "`serde = \"index\"` cannot represent variant `{}`, which captures strings \
+                 without an index; use `serde = \"value\"` or `serde = \"lenient\"`",
+                other.unraw()
+            ),
+        ));
+    }
+    let variants: Vec<SerdeVariant> = variants
+        .iter()
+        .zip(attrs)
+        .map(|(variant, attrs)| {
+            let is_other = |other: &Option<syn::Ident>| other.as_ref() == Some(&variant.ident);
+            let shape = if matches!(variant.fields, syn::Fields::Unit)
+                || is_other(&others.string)
+                || (mode == SerdeMode::Index && is_other(&others.index))
+            {
+                Shape::Unit
+            } else {
+                Shape::Data
+            };
+            let value = attrs
+                .value
+                .clone()
+                .unwrap_or_else(|| options.derive_value(&variant.ident));
+            let aliases = attrs
+                .aliases
+                .iter()
+                .map(|(alias, _)| alias.clone())
+                .collect();
+            SerdeVariant {
+                variant,
+                shape,
+                value,
+                aliases,
+            }
+        })
+        .collect();
+
+    let serialize = serialize_impl(enum_name, generics, &variants, mode);
+    let deserialize = deserialize_impl(
+        enum_name,
+        parse_generics,
+        &variants,
+        repr_ty,
+        mode,
+        options,
+        others,
+    );
+    Ok(quote! {
+        #serialize
+
+        #deserialize
+    })
+}
+
+/// Generates the `Serialize` impl.
+fn serialize_impl(
+    enum_name: &syn::Ident,
+    generics: &syn::Generics,
+    variants: &[SerdeVariant],
+    mode: SerdeMode,
+) -> TokenStream2 {
+    let mut generics = generics.clone();
+    payload_bounds(&mut generics, variants, |ty| {
+        parse_quote_spanned! { ty.span() => #ty: ::serde::Serialize }
+    });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let enum_name_str = enum_name.unraw().to_string();
+
+    let arms = variants.iter().enumerate().map(|(i, serde_variant)| {
+        let SerdeVariant {
+            variant, shape, value, ..
+        } = serde_variant;
+        let ident = &variant.ident;
+        let variant_index = i as u32;
+        let bindings: Vec<syn::Ident> = (0..variant.fields.len())
+            .map(|i| format_ident!("__field{}", i))
+            .collect();
+        let pattern = match (shape, &variant.fields) {
+            (Shape::Unit, _) => quote! { Self::#ident { .. } },
+            (Shape::Data, syn::Fields::Unit) => quote! { Self::#ident },
+            (Shape::Data, syn::Fields::Unnamed(_)) => quote! { Self::#ident(#(#bindings),*) },
+            (Shape::Data, syn::Fields::Named(fields)) => {
+                let names = fields.named.iter().map(|field| &field.ident);
+                quote! { Self::#ident { #(#names: #bindings),* } }
+            }
+        };
+        let body = match (shape, &variant.fields) {
+            (Shape::Unit, _) if mode == SerdeMode::Index => quote! {
+                ::serde::Serialize::serialize(&self.index(), serializer)
+            },
+            (Shape::Unit, _) => quote! { serializer.serialize_str(self.value()) },
+            (Shape::Data, syn::Fields::Unnamed(fields)) if fields.unnamed.len() == 1 => quote! {
+                serializer.serialize_newtype_variant(#enum_name_str, #variant_index, #value, __field0)
+            },
+            (Shape::Data, syn::Fields::Named(fields)) => {
+                let len = fields.named.len();
+                let names = fields
+                    .named
+                    .iter()
+                    .filter_map(|field| field.ident.as_ref())
+                    .map(|ident| ident.unraw().to_string());
+                quote! {
+                    let mut state = serializer.serialize_struct_variant(
+                        #enum_name_str,
+                        #variant_index,
+                        #value,
+                        #len,
+                    )?;
+                    #(::serde::ser::SerializeStructVariant::serialize_field(&mut state, #names, #bindings)?;)*
+                    ::serde::ser::SerializeStructVariant::end(state)
+                }
+            }
+            (Shape::Data, _) => {
+                let len = variant.fields.len();
+                quote! {
+                    let mut state = serializer.serialize_tuple_variant(
+                        #enum_name_str,
+                        #variant_index,
+                        #value,
+                        #len,
+                    )?;
+                    #(::serde::ser::SerializeTupleVariant::serialize_field(&mut state, #bindings)?;)*
+                    ::serde::ser::SerializeTupleVariant::end(state)
+                }
+            }
+        };
+        quote! {
+            #pattern => { #body }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::serde::Serialize for #enum_name #ty_generics #where_clause {
+            fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                __S: ::serde::Serializer,
+            {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    }
+}
+
+/// Generates the `Deserialize` impl.
+///
+/// Strings and integers are converted with the `TryFrom` impls, and single-key maps are read as a
+/// tag followed by the fields of the data variant it names. Struct payloads are read by a second
+/// visitor that knows which variant it builds. `value` mode only accepts the canonical values as
+/// strings and tags, while `lenient` mode also accepts aliases and the spellings of the parse
+/// policy for both.
+///
+/// Enums without data variants ask the deserializer for a string or an integer of the repr type,
+/// so they also work with formats that are not self-describing.
+#[allow(clippy::too_many_arguments)]
+fn deserialize_impl(
+    enum_name: &syn::Ident,
+    generics: &syn::Generics,
+    variants: &[SerdeVariant],
+    repr_ty: &syn::Path,
+    mode: SerdeMode,
+    options: &ContainerAttrs,
+    others: &OtherVariants,
+) -> TokenStream2 {
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let turbofish = ty_generics.as_turbofish();
+    let params = &generics.params;
+    let visitor_where = &generics.where_clause;
+
+    let mut impl_generics = generics.clone();
+    impl_generics.params.insert(0, parse_quote! { '__de });
+    // Borrowed payloads outlive the deserializer's data, as with the derived impls
+    let lifetimes: Vec<syn::Lifetime> = generics
+        .lifetimes()
+        .map(|param| param.lifetime.clone())
+        .collect();
+    for lifetime in lifetimes {
+        impl_generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { '__de: #lifetime });
+    }
+    payload_bounds(&mut impl_generics, variants, |ty| {
+        parse_quote_spanned! { ty.span() => #ty: ::serde::Deserialize<'__de> }
+    });
+    let (de_impl_generics, _, de_where_clause) = impl_generics.split_for_impl();
+
+    let enum_name_str = enum_name.unraw().to_string();
+    let data_variants: Vec<&SerdeVariant> = variants
+        .iter()
+        .filter(|variant| matches!(variant.shape, Shape::Data))
+        .collect();
+    let mut expecting = match mode {
+        SerdeMode::Value => format!("a value of enum {}", enum_name_str),
+        SerdeMode::Index => format!("an index of enum {}", enum_name_str),
+        SerdeMode::Lenient => format!("a value or an index of enum {}", enum_name_str),
+    };
+    if !data_variants.is_empty() {
+        expecting.push_str(", or a map from a value to the fields of its variant");
+    }
+
+    let visit_str = if mode == SerdeMode::Value {
+        // Only the canonical values, whatever aliases and parse policy `TryFrom` accepts
+        let values = variants
+            .iter()
+            .filter(|variant| others.string.as_ref() != Some(&variant.variant.ident))
+            .map(|variant| &variant.value);
+        let unknown = match &others.string {
+            Some(other) => quote! {
+                ::core::result::Result::Ok(#enum_name::#other(::core::convert::Into::into(value)))
+            },
+            None => quote! {
+                ::core::result::Result::Err(__E::unknown_variant(value, VALUES))
+            },
+        };
+        quote! {
+            fn visit_str<__E>(self, value: &str) -> ::core::result::Result<Self::Value, __E>
+            where
+                __E: ::serde::de::Error,
+            {
+                const VALUES: &[&str] = &[#(#values),*];
+                if ::core::iter::Iterator::any(&mut VALUES.iter(), |known| *known == value) {
+                    <#enum_name #ty_generics as ::core::convert::TryFrom<&str>>::try_from(value)
+                        .map_err(__E::custom)
+                } else {
+                    #unknown
+                }
+            }
+        }
+    } else if mode.accepts_strings() {
+        quote! {
+            fn visit_str<__E>(self, value: &str) -> ::core::result::Result<Self::Value, __E>
+            where
+                __E: ::serde::de::Error,
+            {
+                <#enum_name #ty_generics as ::core::convert::TryFrom<&str>>::try_from(value)
+                    .map_err(__E::custom)
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let visit_int = |method: syn::Ident, int: syn::Ident, unexpected: syn::Ident| {
+        quote! {
+            fn #method<__E>(self, value: #int) -> ::core::result::Result<Self::Value, __E>
+            where
+                __E: ::serde::de::Error,
+            {
+                match <#repr_ty as ::core::convert::TryFrom<#int>>::try_from(value) {
+                    ::core::result::Result::Ok(index) => {
+                        <#enum_name #ty_generics as ::core::convert::TryFrom<#repr_ty>>::try_from(index)
+                            .map_err(__E::custom)
+                    }
+                    ::core::result::Result::Err(_) => ::core::result::Result::Err(__E::invalid_value(
+                        ::serde::de::Unexpected::#unexpected(value),
+                        &self,
+                    )),
+                }
+            }
+        }
+    };
+    let visit_ints = if mode.accepts_indices() {
+        let visit_u64 = visit_int(
+            format_ident!("visit_u64"),
+            format_ident!("u64"),
+            format_ident!("Unsigned"),
+        );
+        let visit_i64 = visit_int(
+            format_ident!("visit_i64"),
+            format_ident!("i64"),
+            format_ident!("Signed"),
+        );
+        quote! { #visit_u64 #visit_i64 }
+    } else {
+        quote! {}
+    };
+
+    let tags: Vec<&String> = data_variants
+        .iter()
+        .flat_map(|variant| {
+            let aliases = match mode {
+                SerdeMode::Lenient => variant.aliases.as_slice(),
+                _ => &[],
+            };
+            std::iter::once(&variant.value).chain(aliases)
+        })
+        .collect();
+    let mut struct_arms: Vec<TokenStream2> = Vec::new();
+    let mut next_tag = 0;
+    let tag_arms = data_variants.iter().map(|serde_variant| {
+        let SerdeVariant {
+            variant, aliases, ..
+        } = serde_variant;
+        let ident = &variant.ident;
+        // The value and, in `lenient` mode, the aliases, in the order of `tags`
+        let tag_count = match mode {
+            SerdeMode::Lenient => 1 + aliases.len(),
+            _ => 1,
+        };
+        let positions = next_tag..next_tag + tag_count;
+        next_tag += tag_count;
+        let body = match &variant.fields {
+            syn::Fields::Named(fields) => {
+                let position = struct_arms.len();
+                let (names, keys): (Vec<_>, Vec<_>) = fields
+                    .named
+                    .iter()
+                    .filter_map(|field| field.ident.as_ref())
+                    .map(|ident| (ident, ident.unraw().to_string()))
+                    .unzip();
+                let bindings: Vec<syn::Ident> = (0..names.len())
+                    .map(|i| format_ident!("__field{}", i))
+                    .collect();
+                let tys = fields.named.iter().map(|field| &field.ty);
+                let field_positions = 0..names.len();
+                struct_arms.push(quote! {
+                    #position => {
+                        #(let mut #bindings: ::core::option::Option<#tys> = ::core::option::Option::None;)*
+                        const FIELDS: &[&str] = &[#(#keys),*];
+                        while let ::core::option::Option::Some(key) = map.next_key_seed(__Key { keys: FIELDS, variant: false })? {
+                            match key {
+                                #(
+                                    ::core::option::Option::Some(#field_positions) => {
+                                        if #bindings.is_some() {
+                                            return ::core::result::Result::Err(::serde::de::Error::duplicate_field(#keys));
+                                        }
+                                        #bindings = ::core::option::Option::Some(map.next_value()?);
+                                    }
+                                )*
+                                _ => {
+                                    map.next_value::<::serde::de::IgnoredAny>()?;
+                                }
+                            }
+                        }
+                        #(
+                            let #bindings = match #bindings {
+                                ::core::option::Option::Some(field) => field,
+                                ::core::option::Option::None => return ::core::result::Result::Err(::serde::de::Error::missing_field(#keys)),
+                            };
+                        )*
+                        ::core::result::Result::Ok(#enum_name::#ident { #(#names: #bindings),* })
+                    }
+                });
+                quote! {
+                    map.next_value_seed(__FieldsVisitor #turbofish(#position, ::core::marker::PhantomData))?
+                }
+            }
+            syn::Fields::Unnamed(fields) => {
+                let bindings: Vec<syn::Ident> = (0..fields.unnamed.len())
+                    .map(|i| format_ident!("__field{}", i))
+                    .collect();
+                let tys = fields.unnamed.iter().map(|field| &field.ty);
+                if fields.unnamed.len() == 1 {
+                    quote! { #enum_name::#ident(map.next_value::<#(#tys)*>()?) }
+                } else {
+                    quote! {{
+                        let (#(#bindings,)*) = map.next_value::<(#(#tys,)*)>()?;
+                        #enum_name::#ident(#(#bindings),*)
+                    }}
+                }
+            }
+            syn::Fields::Unit => quote! { #enum_name::#ident },
+        };
+        quote! {
+            #(::core::option::Option::Some(#positions))|* => #body,
+        }
+    });
+    let tag_arms: Vec<TokenStream2> = tag_arms.collect();
+
+    let visit_map = if data_variants.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn visit_map<__A>(self, mut map: __A) -> ::core::result::Result<Self::Value, __A::Error>
+            where
+                __A: ::serde::de::MapAccess<'__de>,
+            {
+                const TAGS: &[&str] = &[#(#tags),*];
+                let tag = match map.next_key_seed(__Key { keys: TAGS, variant: true })? {
+                    ::core::option::Option::Some(tag) => tag,
+                    ::core::option::Option::None => return ::core::result::Result::Err(::serde::de::Error::invalid_length(0, &self)),
+                };
+                let value = match tag {
+                    #(#tag_arms)*
+                    _ => ::core::unreachable!(),
+                };
+                if map.next_key::<::serde::de::IgnoredAny>()?.is_some() {
+                    return ::core::result::Result::Err(::serde::de::Error::invalid_length(2, &self));
+                }
+                ::core::result::Result::Ok(value)
+            }
+        }
+    };
+    // Reads map keys as positions in a list of tags or field names, without allocating
+    let policy = options.parse;
+    let relaxed = policy.case_insensitive || policy.trim || policy.ignore_separators;
+    let tag_matches = if mode == SerdeMode::Lenient && relaxed {
+        let policy = policy.to_tokens(&options.runtime_path());
+        quote! { #policy.matches(value, key) }
+    } else {
+        quote! { *key == value }
+    };
+    let key_seed = if data_variants.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            struct __Key {
+                keys: &'static [&'static str],
+                variant: bool,
+            }
+
+            impl<'__de> ::serde::de::DeserializeSeed<'__de> for __Key {
+                type Value = ::core::option::Option<usize>;
+
+                fn deserialize<__D>(self, deserializer: __D) -> ::core::result::Result<Self::Value, __D::Error>
+                where
+                    __D: ::serde::Deserializer<'__de>,
+                {
+                    deserializer.deserialize_identifier(self)
+                }
+            }
+
+            impl<'__de> ::serde::de::Visitor<'__de> for __Key {
+                type Value = ::core::option::Option<usize>;
+
+                fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    formatter.write_str("a variant or field name")
+                }
+
+                fn visit_str<__E>(self, value: &str) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: ::serde::de::Error,
+                {
+                    let position = if self.variant {
+                        ::core::iter::Iterator::position(&mut self.keys.iter(), |key| #tag_matches)
+                    } else {
+                        ::core::iter::Iterator::position(&mut self.keys.iter(), |key| *key == value)
+                    };
+                    match position {
+                        ::core::option::Option::Some(position) => ::core::result::Result::Ok(::core::option::Option::Some(position)),
+                        ::core::option::Option::None if self.variant => ::core::result::Result::Err(__E::unknown_variant(value, self.keys)),
+                        ::core::option::Option::None => ::core::result::Result::Ok(::core::option::Option::None),
+                    }
+                }
+            }
+        }
+    };
+    let fields_visitor = if struct_arms.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            struct __FieldsVisitor<#params>(usize, ::core::marker::PhantomData<fn() -> #enum_name #ty_generics>)
+            #visitor_where;
+
+            impl #de_impl_generics ::serde::de::DeserializeSeed<'__de> for __FieldsVisitor #ty_generics
+            #de_where_clause
+            {
+                type Value = #enum_name #ty_generics;
+
+                fn deserialize<__D>(self, deserializer: __D) -> ::core::result::Result<Self::Value, __D::Error>
+                where
+                    __D: ::serde::Deserializer<'__de>,
+                {
+                    deserializer.deserialize_map(self)
+                }
+            }
+
+            impl #de_impl_generics ::serde::de::Visitor<'__de> for __FieldsVisitor #ty_generics
+            #de_where_clause
+            {
+                type Value = #enum_name #ty_generics;
+
+                fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    formatter.write_str("the fields of a variant")
+                }
+
+                fn visit_map<__A>(self, mut map: __A) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: ::serde::de::MapAccess<'__de>,
+                {
+                    match self.0 {
+                        #(#struct_arms)*
+                        _ => ::core::unreachable!(),
+                    }
+                }
+            }
+        }
+    };
+
+    // Without data variants the expected type is known, except in `lenient` mode
+    let deserialize_method = match mode {
+        _ if !data_variants.is_empty() => format_ident!("deserialize_any"),
+        SerdeMode::Value => format_ident!("deserialize_str"),
+        SerdeMode::Index => match index_method(repr_ty) {
+            Some(method) => method,
+            None => format_ident!("deserialize_any"),
+        },
+        SerdeMode::Lenient => format_ident!("deserialize_any"),
+    };
+
+    quote! {
+        impl #de_impl_generics ::serde::Deserialize<'__de> for #enum_name #ty_generics
+        #de_where_clause
+        {
+            fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+            where
+                __D: ::serde::Deserializer<'__de>,
+            {
+                struct __Visitor<#params>(::core::marker::PhantomData<fn() -> #enum_name #ty_generics>)
+                #visitor_where;
+
+                impl #de_impl_generics ::serde::de::Visitor<'__de> for __Visitor #ty_generics
+                #de_where_clause
+                {
+                    type Value = #enum_name #ty_generics;
+
+                    fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        formatter.write_str(#expecting)
+                    }
+
+                    #visit_str
+                    #visit_ints
+                    #visit_map
+                }
+
+                #key_seed
+
+                #fields_visitor
+
+                deserializer.#deserialize_method(__Visitor #turbofish(::core::marker::PhantomData))
+            }
+        }
+    }
+}
+
+/// Returns the `Deserializer` method reading the integers `Serialize` writes for indices of
+/// `repr_ty`, for the types whose visitor methods forward to `visit_u64` or `visit_i64`.
+fn index_method(repr_ty: &syn::Path) -> Option<syn::Ident> {
+    let name = repr_ty.segments.last()?.ident.to_string();
+    // Serde writes `usize` and `isize` as 64-bit integers, and `c_int` is an alias of `i32`
+    let int = match name.as_str() {
+        "usize" => "u64",
+        "isize" => "i64",
+        "c_int" => "i32",
+        "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64" => name.as_str(),
+        _ => return None,
+    };
+    Some(format_ident!("deserialize_{}", int))
+}
+
+/// Adds the predicate built by `bound` for every payload type of a data variant to `generics`.
+fn payload_bounds(
+    generics: &mut syn::Generics,
+    variants: &[SerdeVariant],
+    bound: impl Fn(&syn::Type) -> syn::WherePredicate,
+) {
+    let predicates = &mut generics.make_where_clause().predicates;
+    for serde_variant in variants {
+        if matches!(serde_variant.shape, Shape::Data) {
+            for field in &serde_variant.variant.fields {
+                predicates.push(bound(&field.ty));
+            }
+        }
+    }
+}
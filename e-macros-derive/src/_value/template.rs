@@ -0,0 +1,263 @@
+use super::attr::ParsePolicy;
+use quote::format_ident;
+use syn::ext::IdentExt;
+
+/// Something a `#[e(display = "...")]` placeholder refers to.
+pub(crate) enum Placeholder {
+    /// A field of the variant, `{0}` or `{name}`
+    Field(syn::Member),
+    /// The variant's string value, `{value}`
+    Value,
+    /// The variant's index, `{index}`
+    Index,
+}
+
+impl Placeholder {
+    /// Name of the binding a field placeholder is matched into.
+    pub(crate) fn binding(member: &syn::Member) -> syn::Ident {
+        match member {
+            syn::Member::Named(ident) => format_ident!("__field_{}", ident.unraw()),
+            syn::Member::Unnamed(index) => format_ident!("__field{}", index.index),
+        }
+    }
+}
+
+/// Parses a display template against the fields of `variant`.
+///
+/// Returns the template rewritten so that every placeholder names a format argument
+/// `__arg<n>`, together with what each argument refers to. Fields take precedence over the
+/// built-in `value` and `index`, and unknown fields, positional `{}` and unbalanced braces are
+/// reported on the template.
+pub(crate) fn parse(
+    template: &syn::LitStr,
+    variant: &syn::Variant,
+) -> syn::Result<(String, Vec<Placeholder>)> {
+    let source = template.value();
+    let mut rewritten = String::with_capacity(source.len());
+    let mut placeholders: Vec<(String, Placeholder)> = Vec::new();
+    let mut chars = source.chars().peekable();
+    let error = |message: String| syn::Error::new_spanned(template, message);
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                rewritten.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                rewritten.push_str("}}");
+            }
+            '}' => {
+                return Err(error(
+                    "unmatched `}` in display template, use `}}` to escape it".into(),
+                ))
+            }
+            '{' => {
+                let mut inner = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => inner.push(c),
+                        None => {
+                            return Err(error(
+                                "unterminated `{` in display template, use `{{` to escape it"
+                                    .into(),
+                            ))
+                        }
+                    }
+                }
+                let (name, spec) = match inner.split_once(':') {
+                    Some((name, spec)) => (name.trim(), Some(spec)),
+                    None => (inner.trim(), None),
+                };
+                let position = match placeholders.iter().position(|(known, _)| known == name) {
+                    Some(position) => position,
+                    None => {
+                        placeholders
+                            .push((name.to_string(), resolve(name, variant).map_err(error)?));
+                        placeholders.len() - 1
+                    }
+                };
+                rewritten.push_str(&format!("{{__arg{}", position));
+                if let Some(spec) = spec {
+                    rewritten.push(':');
+                    rewritten.push_str(spec);
+                }
+                rewritten.push('}');
+            }
+            c => rewritten.push(c),
+        }
+    }
+
+    Ok((
+        rewritten,
+        placeholders
+            .into_iter()
+            .map(|(_, placeholder)| placeholder)
+            .collect(),
+    ))
+}
+
+/// Resolves a placeholder name against the fields of `variant` and the built-ins.
+fn resolve(name: &str, variant: &syn::Variant) -> Result<Placeholder, String> {
+    if name.is_empty() {
+        return Err(
+            "positional `{}` is not supported in display templates, name a field, `{value}` or `{index}`"
+                .into(),
+        );
+    }
+    match &variant.fields {
+        syn::Fields::Unnamed(fields) => {
+            if let Ok(index) = name.parse::<usize>() {
+                return if index < fields.unnamed.len() {
+                    Ok(Placeholder::Field(syn::Member::Unnamed(index.into())))
+                } else {
+                    Err(format!(
+                        "variant `{}` has no field {}, it has {} field(s)",
+                        variant.ident,
+                        index,
+                        fields.unnamed.len()
+                    ))
+                };
+            }
+        }
+        syn::Fields::Named(fields) => {
+            let field = fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+                .find(|ident| ident.unraw() == name);
+            if let Some(ident) = field {
+                return Ok(Placeholder::Field(syn::Member::Named(ident.clone())));
+            }
+        }
+        syn::Fields::Unit => {}
+    }
+    match name {
+        "value" => Ok(Placeholder::Value),
+        "index" => Ok(Placeholder::Index),
+        _ => {
+            let fields = variant
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| match &field.ident {
+                    Some(ident) => format!("`{{{}}}`", ident.unraw()),
+                    None => format!("`{{{}}}`", i),
+                })
+                .chain(["`{value}`".to_string(), "`{index}`".to_string()])
+                .collect::<Vec<_>>()
+                .join(", ");
+            Err(format!(
+                "unknown placeholder `{{{}}}` in display template of variant `{}` (available: {})",
+                name, variant.ident, fields
+            ))
+        }
+    }
+}
+
+/// A parsed `#[e(pattern = "...")]`: literal text around every field of the variant.
+pub(crate) struct Pattern {
+    /// Text before each field, in the order the fields appear
+    pub(crate) literals: Vec<String>,
+    /// The fields, in the order they appear
+    pub(crate) fields: Vec<syn::Member>,
+    /// Text after the last field
+    pub(crate) suffix: String,
+}
+
+/// Parses a pattern against the fields of `variant`, substituting `{value}` with `value`.
+///
+/// Every field must appear exactly once, without a format spec, and consecutive fields must be
+/// separated by literal text so the generated parser never has to guess where a field ends. Under
+/// `parse(ignore_separators)` that text must contain more than `-` and `_`, which would otherwise
+/// match anywhere.
+pub(crate) fn parse_pattern(
+    pattern: &syn::LitStr,
+    variant: &syn::Variant,
+    value: &str,
+    policy: &ParsePolicy,
+) -> syn::Result<Pattern> {
+    let error = |message: String| syn::Error::new_spanned(pattern, message);
+    // The display template parser already validates the placeholders and handles escapes
+    let (rewritten, placeholders) = parse(pattern, variant)?;
+
+    let mut literals = Vec::new();
+    let mut fields: Vec<syn::Member> = Vec::new();
+    let mut current = String::new();
+    let mut chars = rewritten.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' if chars.peek() == Some(&c) => {
+                chars.next();
+                current.push(c);
+            }
+            '{' => {
+                let inner: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                if inner.contains(':') {
+                    return Err(error("format specs are not supported in patterns".into()));
+                }
+                let position: usize = inner["__arg".len()..].parse().unwrap_or_default();
+                match &placeholders[position] {
+                    Placeholder::Value => current.push_str(value),
+                    Placeholder::Index => {
+                        return Err(error("`{index}` is not supported in patterns".into()))
+                    }
+                    Placeholder::Field(member) => {
+                        if fields.contains(member) {
+                            return Err(error(format!(
+                                "field `{}` appears more than once in the pattern",
+                                quote::quote!(#member)
+                            )));
+                        }
+                        if !fields.is_empty() && current.is_empty() {
+                            return Err(error(
+                                "fields in a pattern must be separated by literal text".into(),
+                            ));
+                        }
+                        if !fields.is_empty()
+                            && policy.ignore_separators
+                            && current.chars().all(|c| c == '-' || c == '_')
+                        {
+                            return Err(error(format!(
+                                "separator {:?} is ignored by `parse(ignore_separators)`, so \
+                                 fields in the pattern need other literal text between them",
+                                current
+                            )));
+                        }
+                        literals.push(std::mem::take(&mut current));
+                        fields.push(member.clone());
+                    }
+                }
+            }
+            c => current.push(c),
+        }
+    }
+
+    let missing: Vec<String> = variant
+        .fields
+        .iter()
+        .enumerate()
+        .filter_map(|(i, field)| {
+            let member = match &field.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(i.into()),
+            };
+            (!fields.contains(&member)).then(|| format!("`{{{}}}`", quote::quote!(#member)))
+        })
+        .collect();
+    if !missing.is_empty() {
+        return Err(error(format!(
+            "the pattern of variant `{}` must contain every field, missing {}",
+            variant.ident,
+            missing.join(", ")
+        )));
+    }
+
+    Ok(Pattern {
+        literals,
+        fields,
+        suffix: current,
+    })
+}
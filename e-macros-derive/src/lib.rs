@@ -0,0 +1,198 @@
+//! Procedural macro implementation for [`e-macros`](https://docs.rs/e-macros).
+//!
+//! The generated code refers to types that live in the `e-macros` crate, so
+//! depend on `e-macros` and use the macros through its re-exports instead of
+//! depending on this crate directly.
+#![allow(
+    clippy::cognitive_complexity,
+    clippy::large_enum_variant,
+    clippy::module_inception,
+    clippy::needless_doctest_main
+)]
+#![warn(
+    missing_debug_implementations,
+    rust_2021_compatibility,
+    unreachable_pub
+)]
+#![deny(unused_must_use)]
+#![doc(test(
+    no_crate_inject,
+    attr(
+        deny(warnings, rust_2021_compatibility),
+        allow(dead_code, unused_variables)
+    )
+))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(docsrs, allow(unused_attributes))]
+#![cfg_attr(any(), allow(dead_code, unreachable_pub))]
+
+extern crate proc_macro;
+mod _value;
+use proc_macro::TokenStream;
+use syn::parse_macro_input;
+
+/// Enhances enum types with additional functionality for serialization, deserialization, debugging, and more.
+///
+/// This macro generates the following for the target enum:
+/// - Implementations of `Debug` and `Display` traits for easy logging and debugging
+/// - Implementation of `TryFrom<&str>` for parsing from strings
+/// - `FromStr`, `AsRef<str>`, `From<Enum>` for the repr type, `From<&Enum>` for `&'static str`,
+///   and `PartialEq` against `&str` and the repr type; each can be opted out of with
+///   `no_from_str`, `no_as_ref`, `no_into_repr`, `no_into_str`, `no_eq_str` and `no_eq_repr`
+/// - Custom value mappings for flexible serialization
+/// - Numeric index support for efficient storage and retrieval
+/// - `to_serde`, `from_serde`, `from_serde_str` and `from_serde_reader` methods for JSON
+///   serialization (when the `serde` feature of e-macros is enabled)
+/// - A structured `e_macros::Error` for failed `TryFrom` conversions
+///
+/// # Features
+///
+/// - **Debug Output**: Provides detailed debug output, including variant names and associated data.
+/// - **Display Implementation**: Customizable string representation for each variant.
+/// - **Serde Integration**: Optional JSON serialization and deserialization support.
+/// - **Numeric Indexing**: Allows efficient mapping between enum variants and numeric values.
+///
+/// # Example: API Status with Serde Support
+/// ```rust
+/// use e_macros::value;
+/// #[value(display)]
+/// #[derive(Debug, PartialEq)]
+/// enum Color {
+///     #[e(value = "RED", index = 0)]
+///     Red,
+///     #[e(value = "GREEN", index = 1)]
+///     Green,
+///     #[e(value = "BLUE", index = 2)]
+///     Blue,
+/// }
+/// fn main() {
+///     let color = Color::Green;
+///     println!("Color value: {}", color.value());
+///     println!("Color index: {}", color.index());
+///     let from_value = Color::try_from("BLUE").unwrap();
+///     println!("From value: {:?}", from_value);
+///     let from_index = Color::try_from(0).unwrap();
+///     println!("From index: {:?}", from_index);
+///     println!("Variant count: {}", Color::variant_count());
+/// }
+/// ```
+/// # Notes
+///
+/// - String conversion is case-sensitive for precise matching, unless relaxed with
+///   `#[value(parse(case_insensitive, trim, ignore_separators))]`; the selected policy is
+///   available as the `PARSE_POLICY` associated const
+/// - Failed conversions return `e_macros::Error`, which records the enum name, the rejected
+///   input and an `e_macros::ErrorKind`
+/// - Custom string values can be specified using `#[e(value = "...")]`; variants without one use
+///   their name, transformed by `#[value(rename_all = "snake_case", prefix = "...", suffix = "...")]`
+/// - Additional strings accepted when parsing can be given with repeated `#[e(alias = "...")]`;
+///   `value()` and `Display` keep returning the canonical value
+/// - Numeric indices can be assigned using `#[e(index = ...)]`; variants without one count up
+///   from the previous index, and an implicit index that overflows the repr type is a compile
+///   error, as is a literal index outside the range of the repr type
+/// - A variant's explicit discriminant is its default index; an `#[e(index = ...)]` that
+///   contradicts it is a compile error unless the enum opts into `allow_index_override`
+/// - Once an enum has an explicit discriminant, variants without `#[e(index = ...)]` take the
+///   discriminant rustc gives them, counting from 0 and from the previous discriminant
+/// - `#[repr(C)]` indices are `core::ffi::c_int`, an integer repr wins when combined with `C`,
+///   reprs that are invalid on enums are compile errors, and the index type is available as
+///   `<Enum as e_macros::Value>::Repr`
+/// - Indices use the `#[repr]` type unless `#[value(index_type = u16)]` picks another integer
+///   type, which then applies to `index()`, the integer `TryFrom` and `INDICES` while the enum
+///   keeps its repr
+/// - `value()`, `index()` and `variant_count()` are `const fn`, alongside a `VARIANT_COUNT`
+///   const and `const fn from_index`, which returns unit variants only; index expressions must
+///   therefore be const-evaluable
+/// - `VALUES` and `INDICES` list every variant's string value and index in declaration order,
+///   unit-only enums also get `VARIANTS`, and `iter()` yields every variant, building data
+///   variants from `Default` payloads
+/// - Data variants can only be converted from a string or an index with `#[e(default_fields)]`
+///   (or `default_fields` on the enum), which fills their payloads with `Default` values;
+///   otherwise the conversion fails with `ErrorKind::RequiresData`
+/// - `#[e(pattern = "NOT_FOUND:{0}")]` parses a data variant from strings matching the pattern,
+///   building each field with its `FromStr` impl; a field that fails to parse is reported as
+///   `ErrorKind::InvalidField`, and the same pattern formats the variant so both round-trip;
+///   the literal text of the pattern is compared under the enum's parse policy, so under
+///   `parse(ignore_separators)` fields cannot be separated by `-` and `_` alone
+/// - An `#[e(other)]` variant of shape `Unknown(String)` or `Unknown(repr)` captures inputs
+///   that match no other variant: the string or integer conversion then becomes `From`, so
+///   `TryFrom` is infallible, and `value()` or `index()` return the captured data; capturing
+///   strings makes `value()` return a `&str` borrowed from the enum instead of a `const fn`
+/// - Two variants sharing a string value or an index is a compile error
+/// - The generated code only refers to `::core` and the e-macros runtime, so it expands in
+///   `#![no_std]` crates; the serde_json helpers come with the `serde` feature, which needs `std`
+/// - Every path in the generated code is absolute, so local items named `Result`, `Option`,
+///   `TryFrom` or `write!` do not interfere; `#[value(crate = "path")]` points it at a re-export
+///   of e-macros when the crate is not a direct dependency
+/// - Generic and lifetime-parameterised enums are supported; every generated impl carries the
+///   enum's generics and where clause
+/// - The `to_serde`, `from_serde`, `from_serde_str` and `from_serde_reader` methods are available
+///   when the `serde` feature of e-macros is enabled, which brings its own `serde_json`
+/// - `serde = "value" | "index" | "lenient"` implements `Serialize` and `Deserialize` from the
+///   values or indices instead of the variant names, tagging data variants by their value;
+///   `value` only accepts the canonical values, `lenient` also accepts aliases, the spellings of
+///   the parse policy and indices, and enums without data variants also work with formats that
+///   are not self-describing; `index` rejects an `other` variant capturing strings
+/// - Debug output includes full details of enum variants and their associated data
+/// - `#[value(display)]` implements `Display` from the custom `value` if specified, otherwise the
+///   variant name, padded according to the formatter's width, fill and alignment;
+///   `display = "index"` or `display = "name"` formats the index or the variant name instead
+/// - `#[value(no_display)]` leaves `Display` to a hand-written impl; without either option,
+///   deriving `Debug` still implies `display`, but that fallback is deprecated and warns
+/// - `#[e(display = "not found: {0}")]` formats a variant from a template whose placeholders
+///   name its fields, `{value}` or `{index}`, checked at compile time
+///
+/// This macro significantly reduces boilerplate code and enhances the functionality
+/// of enums, making them more powerful and easier to use in various scenarios,
+/// especially in applications requiring serialization, configuration management,
+/// and detailed debugging.
+#[proc_macro_attribute]
+pub fn value(args: TokenStream, item: TokenStream) -> TokenStream {
+    // Parse the input enum definition
+    let enum_input = parse_macro_input!(item as syn::ItemEnum);
+    
+    // Generate additional structures and implementations
+    _value::r#enum::create_structure(args.into(), enum_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derive flavour of [`macro@value`], configured through the same `#[e(...)]` variant attributes.
+/// Options that `#[value(...)]` takes as arguments go in an `#[e(...)]` attribute on the enum.
+///
+/// Unlike the attribute macro, the derive leaves the enum exactly as written: it does not
+/// re-emit the enum, merge `#[derive]` lists or reorder attributes, which keeps it compatible
+/// with other attribute macros and with IDE tooling. It generates the same `TryFrom<&str>`,
+/// `TryFrom<repr>`, `value()`, `index()` and `variant_count()` items.
+///
+/// As with `#[value]`, `Display` is only implemented with `#[e(display)]`, `#[e(display = "...")]`
+/// or variant templates. A derive macro cannot see the other derives of the enum, so deriving
+/// `Debug` does not imply it, and the `to_serde`/`from_serde` helpers are not generated.
+///
+/// # Example
+/// ```rust
+/// use e_macros::Value;
+///
+/// #[derive(Debug, PartialEq, Value)]
+/// #[e(display)]
+/// #[repr(u8)]
+/// enum Color {
+///     #[e(value = "RED", index = 0)]
+///     Red,
+///     #[e(value = "GREEN", index = 1)]
+///     Green,
+/// }
+/// fn main() {
+///     assert_eq!(Color::Green.value(), "GREEN");
+///     assert_eq!(Color::try_from(0u8), Ok(Color::Red));
+///     assert_eq!(Color::Red.to_string(), "RED");
+/// }
+/// ```
+#[proc_macro_derive(Value, attributes(e))]
+pub fn derive_value(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as syn::DeriveInput);
+
+    _value::r#enum::derive_structure(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
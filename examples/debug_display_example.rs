@@ -1,7 +1,7 @@
 use e_macros::value;
 
 // Define the Config enum
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq)]
 enum Config {
     #[e(value = "database")]
@@ -13,7 +13,7 @@ enum Config {
 }
 
 // Define the LogLevel enum
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq)]
 enum LogLevel {
     #[e(value = "debug")]
@@ -23,7 +23,7 @@ enum LogLevel {
 }
 
 // Define the LinkedList enum
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq)]
 enum LinkedList {
     #[e(value = "cons")]
@@ -1,6 +1,6 @@
 use std::convert::TryFrom;
 
-#[e_macros::value]
+#[e_macros::value(display)]
 #[derive(Debug, PartialEq)]
 enum Status {
     #[e(value = "OK", index = 200)]
@@ -22,7 +22,7 @@ fn main() {
 
     // To string
     let status = Status::Error2("Server error".to_string());
-    println!("To string: {}", status.to_string());
+    println!("To string: {}", status);
 
     // To index
     println!("To index: {}", status.index());
@@ -1,4 +1,4 @@
-#[e_macros::value]
+#[e_macros::value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(i8)]
 pub enum TestEnumI8 {
@@ -9,6 +9,7 @@ pub enum TestEnumI8 {
     #[e(index = 126)]
     N1,
     N2,
+    // The discriminant is also the index, so `N3 as i8` and `N3.index()` agree
     N3 = 100
 }
 
@@ -1,7 +1,7 @@
 use e_macros::value;
 use serde::{Serialize, Deserialize};
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 enum ApiStatus {
     #[e(value = "OK", index = 200)]
@@ -1,6 +1,6 @@
 use e_macros::value;
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq)]
 enum Color {
     #[e(value = "RED", index = 0)]
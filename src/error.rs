@@ -0,0 +1,288 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::fmt;
+
+/// Error returned by the `TryFrom<&str>` and `TryFrom<repr>` conversions generated by
+/// [`value`](crate::value).
+///
+/// It records which enum rejected the conversion, the rejected input and why it was rejected.
+/// Rejected strings, returned by [`Error::value`], and the reason of [`ErrorKind::InvalidField`]
+/// are only recorded with the `alloc` feature, which is enabled by the default `std` feature.
+///
+/// # Example
+/// ```rust
+/// use e_macros::{value, ErrorKind, Input};
+///
+/// #[value(display)]
+/// #[derive(Debug, PartialEq)]
+/// enum Color {
+///     #[e(value = "RED", index = 0)]
+///     Red,
+///     #[e(value = "CUSTOM", index = 1)]
+///     Custom(u32),
+/// }
+///
+/// fn main() {
+///     let err = Color::try_from("BLUE").unwrap_err();
+///     assert_eq!(err.enum_name(), "Color");
+///     assert_eq!(err.input(), &Input::Str);
+///     assert_eq!(err.value(), Some("BLUE"));
+///     assert_eq!(err.kind(), ErrorKind::UnknownValue);
+///     assert_eq!(err.to_string(), r#"Invalid string value "BLUE" for enum "Color""#);
+///
+///     let err = Color::try_from(1).unwrap_err();
+///     assert_eq!(err.kind(), ErrorKind::RequiresData { variant: "Custom" });
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Error {
+    enum_name: &'static str,
+    input: Input,
+    kind: ErrorKind,
+    #[cfg(feature = "alloc")]
+    recorded: Option<Box<Recorded>>,
+}
+
+/// The rejected string of an [`Error`] and the reason of [`ErrorKind::InvalidField`], boxed so
+/// that `Result`s carrying an `Error` stay small.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Recorded {
+    value: String,
+    reason: Option<String>,
+}
+
+/// The input rejected by a failed conversion.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Input {
+    /// A string passed to `TryFrom<&str>`, which [`Error::value`] returns with the `alloc`
+    /// feature.
+    Str,
+    /// A signed integer passed to `TryFrom<repr>`.
+    Int(i128),
+    /// An unsigned integer passed to `TryFrom<repr>`.
+    UInt(u128),
+}
+
+/// The reason a conversion failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// No variant has the given string value.
+    UnknownValue,
+    /// No variant has the given index.
+    UnknownIndex,
+    /// The input matches a variant that carries data, so it cannot be built from the input alone.
+    RequiresData {
+        /// Name of the matched variant.
+        variant: &'static str,
+    },
+    /// The input matches the `#[e(pattern = "...")]` of a variant, but the text of one of its
+    /// fields could not be parsed.
+    InvalidField {
+        /// Name of the matched variant.
+        variant: &'static str,
+        /// Name of the field, or its position for tuple variants.
+        field: &'static str,
+    },
+}
+
+impl Error {
+    /// Creates an error for a string that matches no variant of `enum_name`.
+    pub fn unknown_value(enum_name: &'static str, value: &str) -> Self {
+        Self::for_str(enum_name, value, ErrorKind::UnknownValue, None)
+    }
+
+    /// Creates an error for an index that matches no variant of `enum_name`.
+    pub fn unknown_index(enum_name: &'static str, index: impl Into<Input>) -> Self {
+        Self {
+            enum_name,
+            input: index.into(),
+            kind: ErrorKind::UnknownIndex,
+            #[cfg(feature = "alloc")]
+            recorded: None,
+        }
+    }
+
+    /// Creates an error for an input that matches `variant`, which carries data.
+    ///
+    /// Strings passed here are not recorded; use [`Error::value_requires_data`] for them.
+    pub fn requires_data(
+        enum_name: &'static str,
+        variant: &'static str,
+        input: impl Into<Input>,
+    ) -> Self {
+        Self {
+            enum_name,
+            input: input.into(),
+            kind: ErrorKind::RequiresData { variant },
+            #[cfg(feature = "alloc")]
+            recorded: None,
+        }
+    }
+
+    /// Creates an error for a string that matches `variant`, which carries data.
+    pub fn value_requires_data(
+        enum_name: &'static str,
+        variant: &'static str,
+        value: &str,
+    ) -> Self {
+        Self::for_str(enum_name, value, ErrorKind::RequiresData { variant }, None)
+    }
+
+    /// Creates an error for a string that matches the pattern of `variant`, but whose text for
+    /// `field` was rejected by the field type's `FromStr` with `reason`.
+    pub fn invalid_field(
+        enum_name: &'static str,
+        variant: &'static str,
+        field: &'static str,
+        value: &str,
+        reason: impl fmt::Display,
+    ) -> Self {
+        Self::for_str(
+            enum_name,
+            value,
+            ErrorKind::InvalidField { variant, field },
+            Some(&reason),
+        )
+    }
+
+    /// Creates an error for the string `value`, recording it with the `alloc` feature.
+    fn for_str(
+        enum_name: &'static str,
+        value: &str,
+        kind: ErrorKind,
+        reason: Option<&dyn fmt::Display>,
+    ) -> Self {
+        #[cfg(not(feature = "alloc"))]
+        let _ = (value, reason);
+        Self {
+            enum_name,
+            input: Input::Str,
+            kind,
+            #[cfg(feature = "alloc")]
+            recorded: Some(Box::new(Recorded {
+                value: value.to_string(),
+                reason: reason.map(ToString::to_string),
+            })),
+        }
+    }
+
+    /// Returns the name of the enum that rejected the conversion.
+    pub fn enum_name(&self) -> &'static str {
+        self.enum_name
+    }
+
+    /// Returns the rejected input.
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    /// Returns the rejected string, for conversions from `&str`.
+    #[cfg(feature = "alloc")]
+    pub fn value(&self) -> Option<&str> {
+        self.recorded.as_ref().map(|recorded| recorded.value.as_str())
+    }
+
+    /// Returns the reason the conversion failed.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the message of the field's parse error, for [`ErrorKind::InvalidField`].
+    #[cfg(feature = "alloc")]
+    pub fn reason(&self) -> Option<&str> {
+        self.recorded.as_ref()?.reason.as_deref()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let input = Shown(self);
+        match (self.kind, &self.input) {
+            (ErrorKind::UnknownValue, _) => write!(
+                f,
+                "Invalid string value {} for enum \"{}\"",
+                input, self.enum_name
+            ),
+            (ErrorKind::UnknownIndex, _) => write!(
+                f,
+                "Invalid value {} for enum \"{}\"",
+                input, self.enum_name
+            ),
+            (ErrorKind::RequiresData { variant }, Input::Str) => write!(
+                f,
+                "value {} of enum \"{}\" maps to {}, which requires data",
+                input, self.enum_name, variant
+            ),
+            (ErrorKind::RequiresData { variant }, _) => write!(
+                f,
+                "index {} of enum \"{}\" maps to {}, which requires data",
+                input, self.enum_name, variant
+            ),
+            (ErrorKind::InvalidField { variant, field }, _) => {
+                write!(
+                    f,
+                    "value {} of enum \"{}\" matches the pattern of {}, but field {} is invalid",
+                    input, self.enum_name, variant, field
+                )?;
+                #[cfg(feature = "alloc")]
+                if let Some(reason) = self.reason() {
+                    write!(f, ": {}", reason)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// The rejected input of an [`Error`] as shown in its message, quoting the recorded string if any.
+struct Shown<'a>(&'a Error);
+
+impl fmt::Display for Shown<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "alloc")]
+        if let Some(value) = self.0.value() {
+            return write!(f, "{:?}", value);
+        }
+        self.0.input.fmt(f)
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Str => f.write_str("(string)"),
+            Input::Int(i) => write!(f, "{}", i),
+            Input::UInt(u) => write!(f, "{}", u),
+        }
+    }
+}
+
+impl From<&str> for Input {
+    fn from(_: &str) -> Self {
+        Input::Str
+    }
+}
+
+macro_rules! impl_input_from_int {
+    ($variant:ident: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for Input {
+                fn from(value: $ty) -> Self {
+                    Input::$variant(value as _)
+                }
+            }
+        )*
+    };
+}
+
+impl_input_from_int!(Int: i8, i16, i32, i64, i128, isize);
+impl_input_from_int!(UInt: u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,64 @@
+/// How the generated `TryFrom<&str>` matches input strings against variant values.
+///
+/// Selected with `#[value(parse(...))]` and exposed as the `PARSE_POLICY` associated const of
+/// the enum. Matching never allocates.
+///
+/// # Example
+/// ```rust
+/// #[e_macros::value(parse(case_insensitive, trim, ignore_separators), display)]
+/// #[derive(Debug, PartialEq)]
+/// enum Level {
+///     #[e(value = "LOG_LEVEL_DEBUG")]
+///     Debug,
+///     #[e(value = "LOG_LEVEL_INFO")]
+///     Info,
+/// }
+///
+/// fn main() {
+///     assert!(Level::PARSE_POLICY.case_insensitive);
+///     assert_eq!(Level::try_from("  log-level-info\n").unwrap(), Level::Info);
+///     assert_eq!(Level::try_from("LogLevelDebug").unwrap(), Level::Debug);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ParsePolicy {
+    /// Letters match regardless of case.
+    pub case_insensitive: bool,
+    /// Leading and trailing whitespace is ignored.
+    pub trim: bool,
+    /// `-` and `_` separators are ignored.
+    pub ignore_separators: bool,
+}
+
+impl ParsePolicy {
+    /// Exact matching, the default.
+    pub const EXACT: ParsePolicy = ParsePolicy {
+        case_insensitive: false,
+        trim: false,
+        ignore_separators: false,
+    };
+
+    /// Returns whether this policy is [`ParsePolicy::EXACT`].
+    pub const fn is_exact(&self) -> bool {
+        !self.case_insensitive && !self.trim && !self.ignore_separators
+    }
+
+    /// Returns whether `input` matches the variant value `expected` under this policy.
+    pub fn matches(&self, input: &str, expected: &str) -> bool {
+        let (input, expected) = if self.trim {
+            (input.trim(), expected.trim())
+        } else {
+            (input, expected)
+        };
+        let keep = |c: &char| !(self.ignore_separators && (*c == '-' || *c == '_'));
+        if self.case_insensitive {
+            input
+                .chars()
+                .filter(keep)
+                .flat_map(char::to_lowercase)
+                .eq(expected.chars().filter(keep).flat_map(char::to_lowercase))
+        } else {
+            input.chars().filter(keep).eq(expected.chars().filter(keep))
+        }
+    }
+}
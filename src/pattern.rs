@@ -0,0 +1,102 @@
+use crate::ParsePolicy;
+
+/// Splits `input` around the literal text of an `#[e(pattern = "...")]`, returning the text of
+/// each of its `N` fields.
+///
+/// `literals[i]` is the text before field `i`, so the prefix and then the separators, and
+/// `suffix` the text after the last field. Separators are never empty, so each field extends up
+/// to the first occurrence of the next separator, and the last field up to the suffix. The
+/// literal text is compared under `policy`, while trimming is left to the caller.
+#[doc(hidden)]
+pub fn match_pattern<'a, const N: usize>(
+    input: &'a str,
+    literals: [&str; N],
+    suffix: &str,
+    policy: &ParsePolicy,
+) -> Option<[&'a str; N]> {
+    let mut fields = [""; N];
+    let mut rest = input;
+    for (i, literal) in literals.iter().enumerate() {
+        rest = strip_literal(rest, literal, policy)?;
+        match literals.get(i + 1) {
+            Some(separator) => {
+                let end = find_literal(rest, separator, policy)?;
+                fields[i] = &rest[..end];
+                rest = &rest[end..];
+            }
+            None => {
+                fields[i] = strip_suffix(rest, suffix, policy)?;
+                return Some(fields);
+            }
+        }
+    }
+    strip_suffix(rest, suffix, policy)
+        .is_some_and(str::is_empty)
+        .then_some(fields)
+}
+
+/// Returns whether `c` is skipped when comparing literal text under `policy`.
+fn is_ignored(c: char, policy: &ParsePolicy) -> bool {
+    policy.ignore_separators && (c == '-' || c == '_')
+}
+
+/// Strips `literal` from the start of `input`, comparing under `policy`.
+fn strip_literal<'a>(input: &'a str, literal: &str, policy: &ParsePolicy) -> Option<&'a str> {
+    if !policy.case_insensitive && !policy.ignore_separators {
+        return input.strip_prefix(literal);
+    }
+    let mut expected = literal.chars().filter(|c| !is_ignored(*c, policy)).peekable();
+    let mut chars = input.char_indices();
+    while expected.peek().is_some() {
+        let (_, c) = chars.next()?;
+        if is_ignored(c, policy) {
+            continue;
+        }
+        let wanted = expected.next()?;
+        let same = if policy.case_insensitive {
+            c.to_lowercase().eq(wanted.to_lowercase())
+        } else {
+            c == wanted
+        };
+        if !same {
+            return None;
+        }
+    }
+    // Separators ending the literal also take those of the input, which a field such as a
+    // negative number may otherwise start with
+    let rest = chars.as_str();
+    if literal.chars().next_back().is_some_and(|c| is_ignored(c, policy)) {
+        Some(rest.trim_start_matches(|c| is_ignored(c, policy)))
+    } else {
+        Some(rest)
+    }
+}
+
+/// Finds the byte offset of the first occurrence of `literal` in `input`, comparing under
+/// `policy`.
+fn find_literal(input: &str, literal: &str, policy: &ParsePolicy) -> Option<usize> {
+    if !policy.case_insensitive && !policy.ignore_separators {
+        return input.find(literal);
+    }
+    input
+        .char_indices()
+        .map(|(i, _)| i)
+        .find(|i| strip_literal(&input[*i..], literal, policy).is_some())
+}
+
+/// Strips `suffix` from the end of `input`, comparing under `policy`; separators left around it
+/// are ignored too.
+fn strip_suffix<'a>(input: &'a str, suffix: &str, policy: &ParsePolicy) -> Option<&'a str> {
+    if !policy.case_insensitive && !policy.ignore_separators {
+        return input.strip_suffix(suffix);
+    }
+    input
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain([input.len()])
+        .find(|i| {
+            strip_literal(&input[*i..], suffix, policy)
+                .is_some_and(|rest| rest.chars().all(|c| is_ignored(c, policy)))
+        })
+        .map(|i| &input[..i])
+}
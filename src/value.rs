@@ -0,0 +1,27 @@
+/// Implemented for every enum expanded by `#[value]` or `#[derive(Value)]`.
+///
+/// Inherent associated types are not available on stable Rust, so the index type chosen from
+/// `#[repr(...)]` or `index_type` is exposed through this trait.
+///
+/// # Example
+/// ```rust
+/// use core::ffi::c_int;
+/// use e_macros::Value;
+///
+/// #[e_macros::value]
+/// #[repr(C)]
+/// enum Color {
+///     Red = 1,
+///     Green,
+/// }
+///
+/// fn main() {
+///     let index: <Color as Value>::Repr = Color::Green.index();
+///     assert_eq!(index, 2 as c_int);
+///     assert_eq!(index, Color::Green as c_int);
+/// }
+/// ```
+pub trait Value {
+    /// The type of `index()`, of the integer `TryFrom` and of `INDICES`.
+    type Repr;
+}
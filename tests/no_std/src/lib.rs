@@ -0,0 +1,63 @@
+#![no_std]
+#![deny(warnings)]
+
+use core::str::FromStr;
+
+#[e_macros::value(parse(case_insensitive, trim), display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum Color {
+    #[e(value = "RED", alias = "r")]
+    Red,
+    #[e(value = "GREEN")]
+    Green = 10,
+    #[e(display = "blue #{index}")]
+    Blue,
+}
+
+#[e_macros::value(default_fields, display)]
+#[derive(Debug, PartialEq)]
+pub enum Reading {
+    #[e(value = "none")]
+    Nothing,
+    #[e(value = "temp", pattern = "temp={0}")]
+    Temperature(i16),
+    #[e(value = "pos", pattern = "pos({x},{y})")]
+    Position { x: u16, y: u16 },
+}
+
+#[e_macros::value(index_type = u16, display)]
+#[derive(Debug, PartialEq)]
+pub enum Code {
+    #[e(value = "ok", index = 200)]
+    Ok,
+    #[e(other)]
+    Unknown(u16),
+}
+
+#[derive(Debug, PartialEq, e_macros::Value)]
+pub enum Derived {
+    #[e(value = "a")]
+    A,
+    B,
+}
+
+pub fn parse_color(input: &str) -> Option<Color> {
+    Color::from_str(input).ok()
+}
+
+pub fn parse_reading(input: &str) -> Result<Reading, e_macros::Error> {
+    Reading::try_from(input)
+}
+
+pub fn code(index: u16) -> Code {
+    Code::from(index)
+}
+
+pub fn indices() -> impl Iterator<Item = u8> {
+    Color::iter().map(|color| color.index())
+}
+
+pub fn derived_value(derived: &Derived) -> &'static str {
+    derived.value()
+}
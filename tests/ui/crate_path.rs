@@ -0,0 +1,6 @@
+#[e_macros::value(crate = "not a path")]
+enum Color {
+    Red,
+}
+
+fn main() {}
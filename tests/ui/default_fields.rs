@@ -0,0 +1,9 @@
+struct NoDefault;
+
+#[e_macros::value]
+enum Slot {
+    #[e(default_fields)]
+    Full(NoDefault),
+}
+
+fn main() {}
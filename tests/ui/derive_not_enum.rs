@@ -0,0 +1,6 @@
+#[derive(e_macros::Value)]
+struct Color {
+    red: u8,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#[e_macros::value(display = "debug")]
+enum UnknownMode {
+    A,
+}
+
+#[e_macros::value(display, no_display)]
+enum Conflicting {
+    A,
+}
+
+#[e_macros::value(no_display)]
+enum UnusedTemplate {
+    #[e(display = "a {value}")]
+    A,
+}
+
+fn main() {}
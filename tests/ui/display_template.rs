@@ -0,0 +1,16 @@
+#[e_macros::value(display)]
+#[derive(Debug)]
+enum Status {
+    #[e(display = "missing {1}")]
+    Tuple(String),
+    #[e(display = "unknown {reason}")]
+    Named { message: String },
+    #[e(display = "positional {}")]
+    Positional,
+    #[e(display = "open {value")]
+    Unterminated,
+    #[e(display = "close value}")]
+    Unmatched,
+}
+
+fn main() {}
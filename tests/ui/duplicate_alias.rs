@@ -0,0 +1,21 @@
+#[e_macros::value]
+enum Color {
+    #[e(value = "RED", alias = "CRIMSON")]
+    Red,
+    #[e(value = "DARK_RED", alias = "CRIMSON")]
+    DarkRed,
+    #[e(value = "BLUE", alias = "RED")]
+    Blue,
+    #[e(alias = 5)]
+    Green,
+}
+
+#[e_macros::value(parse(case_insensitive))]
+enum Level {
+    #[e(value = "warning")]
+    Warning,
+    #[e(value = "error", alias = "WARNING")]
+    Error,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+const BASE: u8 = 3;
+
+#[e_macros::value]
+#[repr(u8)]
+enum Status {
+    #[e(index = BASE)]
+    A,
+    #[e(index = BASE)]
+    B,
+    C,
+    #[e(index = 4)]
+    D,
+}
+
+fn main() {}
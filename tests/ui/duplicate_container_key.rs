@@ -0,0 +1,33 @@
+#[e_macros::value(rename_all = "snake_case", rename_all = "kebab-case")]
+enum RenameAll {
+    A,
+}
+
+#[e_macros::value(serde = "value", serde = "index")]
+enum Serde {
+    A,
+}
+
+#[e_macros::value(index_type = u8, index_type = u16)]
+enum IndexType {
+    A,
+}
+
+#[e_macros::value(crate = "::e_macros", crate = "::other")]
+enum Crate {
+    A,
+}
+
+#[e_macros::value(prefix = "a_", prefix = "b_")]
+enum Prefix {
+    A,
+}
+
+#[derive(e_macros::Value)]
+#[e(suffix = "_a")]
+#[e(suffix = "_b")]
+enum Suffix {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#[e_macros::value]
+#[repr(u16)]
+enum Status {
+    #[e(value = "OK", index = 200)]
+    Ok,
+    #[e(value = "CREATED")]
+    Created,
+    #[e(value = "ACCEPTED", index = 201)]
+    Accepted,
+    #[e(value = "ERROR", index = 500)]
+    Error,
+    #[e(value = "FAILURE", index = 500)]
+    Failure(String),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[e_macros::value]
+enum Color {
+    #[e(value = "RED")]
+    Red,
+    #[e(value = "RED")]
+    Crimson,
+    Blue,
+    #[e(value = "Blue")]
+    Navy,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#[e_macros::value]
+#[repr(u8)]
+enum Flag {
+    #[e(index = 10)]
+    Read = 1,
+    Write = 2,
+}
+
+#[derive(e_macros::Value)]
+#[repr(u8)]
+enum Duplicate {
+    A = 3,
+    #[e(index = 3)]
+    B = 4,
+}
+
+fn main() {}
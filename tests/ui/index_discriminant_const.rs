@@ -0,0 +1,11 @@
+const BASE: u8 = 4;
+
+#[e_macros::value]
+#[repr(u8)]
+enum Level {
+    #[e(index = 5)]
+    Low = BASE,
+    High,
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+#[e_macros::value]
+#[repr(u8)]
+enum Small {
+    #[e(index = 300)]
+    TooBig,
+    #[e(value = "negative", index = -1)]
+    Negative,
+    #[e(index = 255)]
+    Max,
+}
+
+#[e_macros::value]
+#[repr(i16)]
+enum Signed {
+    #[e(index = -32769)]
+    TooSmall,
+    #[e(index = -32768)]
+    Min,
+}
+
+#[e_macros::value]
+#[repr(usize)]
+enum Pointer {
+    #[e(index = -5)]
+    Negative,
+}
+
+#[derive(e_macros::Value)]
+#[repr(u16)]
+enum Derived {
+    #[e(index = 65536)]
+    TooBig,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#[e_macros::value]
+#[repr(u8)]
+enum Literal {
+    #[e(index = 254)]
+    A,
+    B,
+    C,
+}
+
+const BASE: i8 = 126;
+
+#[e_macros::value]
+#[repr(i8)]
+enum Constant {
+    #[e(index = BASE)]
+    A,
+    B,
+    C,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[e_macros::value(index_type = f32)]
+enum Float {
+    A,
+}
+
+#[e_macros::value(index_type = u8)]
+#[repr(u16)]
+enum Narrow {
+    #[e(index = 256)]
+    A,
+}
+
+fn main() {}
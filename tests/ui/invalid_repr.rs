@@ -0,0 +1,31 @@
+#[e_macros::value]
+#[repr(u8, u16)]
+enum Conflicting {
+    A,
+}
+
+#[e_macros::value]
+#[repr(packed)]
+enum Packed {
+    A,
+}
+
+#[e_macros::value]
+#[repr(transparent, u8)]
+enum Transparent {
+    A,
+}
+
+#[e_macros::value]
+#[repr(simd)]
+enum Simd {
+    A,
+}
+
+#[e_macros::value]
+#[repr(u8 = 1)]
+enum Malformed {
+    A,
+}
+
+fn main() {}
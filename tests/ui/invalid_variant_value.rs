@@ -0,0 +1,19 @@
+#[e_macros::value]
+enum Color {
+    #[e(value = 5)]
+    Red,
+}
+
+#[e_macros::value]
+enum Size {
+    #[e(index = )]
+    Small,
+}
+
+#[e_macros::value]
+enum Shape {
+    #[e(value = "CIRCLE", value = "ROUND")]
+    Circle,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#[e_macros::value]
+#[repr(u8)]
+enum Shape {
+    #[e(other)]
+    Unknown(u32),
+}
+
+#[e_macros::value]
+enum Twice {
+    #[e(other)]
+    First(String),
+    #[e(other)]
+    Second(String),
+}
+
+#[e_macros::value]
+enum Named {
+    #[e(other)]
+    Unknown { value: String },
+}
+
+fn main() {}
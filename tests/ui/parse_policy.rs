@@ -0,0 +1,19 @@
+#[e_macros::value(parse(case_insensitive, ignore_separators))]
+enum Header {
+    #[e(value = "Content-Type")]
+    ContentType,
+    #[e(value = "content_type")]
+    ContentTypeLegacy,
+}
+
+#[e_macros::value(parse(case_insenstive))]
+enum Color {
+    Red,
+}
+
+#[e_macros::value(prase(trim))]
+enum Size {
+    Small,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+#[e_macros::value(display)]
+#[derive(Debug)]
+enum Status {
+    #[e(pattern = "pair({0}{1})")]
+    Adjacent(u8, u8),
+    #[e(pattern = "twice({0},{0})")]
+    Repeated(u8),
+    #[e(pattern = "only({0})")]
+    Missing(u8, u8),
+    #[e(pattern = "spec({0:>4})")]
+    Spec(u8),
+    #[e(pattern = "at({index})")]
+    Index,
+    #[e(pattern = "unknown({1})")]
+    Unknown(u8),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[e_macros::value(parse(ignore_separators), display)]
+#[derive(Debug)]
+enum Span {
+    #[e(pattern = "range:{0}-{1}")]
+    Range(u8, u8),
+    #[e(pattern = "pair:{0}_{1}")]
+    Pair(String, String),
+    #[e(pattern = "to:{0}-to-{1}")]
+    To(u8, u8),
+}
+
+fn main() {}
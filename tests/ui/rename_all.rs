@@ -0,0 +1,13 @@
+#[e_macros::value(rename_all = "snake")]
+enum Color {
+    Red,
+}
+
+#[e_macros::value(rename_all = "lowercase")]
+enum Size {
+    Small,
+    #[e(value = "small")]
+    Tiny,
+}
+
+fn main() {}
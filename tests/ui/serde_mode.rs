@@ -0,0 +1,21 @@
+#[e_macros::value(serde = "name")]
+enum Unknown {
+    A,
+}
+
+#[e_macros::value(serde = "value", display)]
+#[derive(Debug, Serialize)]
+enum Derived {
+    A,
+}
+
+#[e_macros::value(serde = "index", display)]
+#[derive(Debug)]
+enum Proto {
+    #[e(index = 1)]
+    Http,
+    #[e(other)]
+    Unknown(String),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[e_macros::value]
+enum Color {
+    #[e(vaule = "RED")]
+    Red,
+    #[e(value = "GREEN", idx = 2)]
+    Green,
+    #[e(colour = "BLUE")]
+    Blue,
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+#![allow(dead_code)]
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(u8)]
+enum Color {
+    #[e(value = "RED", alias = "red", alias = "CRIMSON")]
+    Red,
+    #[e(value = "GREEN")]
+    #[e(alias = "LIME")]
+    Green,
+    #[e(alias = "NAVY")]
+    Blue,
+    #[e(value = "CUSTOM", alias = "OTHER")]
+    Custom(u32),
+}
+
+#[e_macros::value(parse(case_insensitive), display)]
+#[derive(Debug, PartialEq)]
+enum Level {
+    #[e(value = "warning", alias = "warn")]
+    Warning,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use e_macros::ErrorKind;
+
+    #[test]
+    fn test_alias_parsing() {
+        assert_eq!(Color::try_from("RED"), Ok(Color::Red));
+        assert_eq!(Color::try_from("red"), Ok(Color::Red));
+        assert_eq!(Color::try_from("CRIMSON"), Ok(Color::Red));
+        assert_eq!(Color::try_from("LIME"), Ok(Color::Green));
+        assert_eq!(Color::try_from("Blue"), Ok(Color::Blue));
+        assert_eq!(Color::try_from("NAVY"), Ok(Color::Blue));
+        assert_eq!(
+            Color::try_from("OTHER").unwrap_err().kind(),
+            ErrorKind::RequiresData { variant: "Custom" }
+        );
+        assert!(Color::try_from("crimson").is_err());
+    }
+
+    #[test]
+    fn test_canonical_value() {
+        assert_eq!(Color::try_from("CRIMSON").unwrap().value(), "RED");
+        assert_eq!(Color::try_from("NAVY").unwrap().to_string(), "Blue");
+        assert_eq!(Color::variant_count(), 4);
+    }
+
+    #[test]
+    fn test_alias_with_policy() {
+        assert_eq!(Level::try_from("WARN"), Ok(Level::Warning));
+        assert_eq!(Level::try_from("Warning"), Ok(Level::Warning));
+    }
+}
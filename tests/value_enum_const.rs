@@ -0,0 +1,99 @@
+#![allow(dead_code)]
+
+#[e_macros::value(display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Color {
+    #[e(value = "RED", index = 10)]
+    Red,
+    #[e(value = "GREEN")]
+    Green,
+    #[e(value = "BLUE", index = 3)]
+    Blue,
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+enum Shape {
+    #[e(value = "empty")]
+    Empty,
+    #[e(value = "circle")]
+    Circle(u32),
+    #[e(value = "rect")]
+    Rect { w: u32, h: u32 },
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+enum Wrapper<T> {
+    #[e(value = "empty")]
+    Empty,
+    #[e(value = "item")]
+    Item(T),
+}
+
+#[derive(Debug, PartialEq, e_macros::Value)]
+enum Derived {
+    A,
+    #[e(index = 7)]
+    B,
+}
+
+const GREEN_VALUE: &str = Color::Green.value();
+const GREEN_INDEX: u8 = Color::Green.index();
+const COUNT: usize = Color::variant_count();
+const BLUE: Option<Color> = Color::from_index(3);
+const WEIGHTS: [u32; Color::VARIANT_COUNT] = [0; Color::VARIANT_COUNT];
+static LOOKUP: [&str; Color::variant_count()] = [
+    Color::Red.value(),
+    Color::Green.value(),
+    Color::Blue.value(),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_const_accessors() {
+        assert_eq!(GREEN_VALUE, "GREEN");
+        assert_eq!(GREEN_INDEX, 11);
+        assert_eq!(COUNT, 3);
+        assert_eq!(WEIGHTS.len(), 3);
+        assert_eq!(LOOKUP, ["RED", "GREEN", "BLUE"]);
+    }
+
+    #[test]
+    fn test_variant_count() {
+        assert_eq!(Color::VARIANT_COUNT, Color::variant_count());
+        assert_eq!(Shape::VARIANT_COUNT, 3);
+        assert_eq!(Wrapper::<String>::VARIANT_COUNT, 2);
+        assert_eq!(Derived::VARIANT_COUNT, 2);
+    }
+
+    #[test]
+    fn test_from_index() {
+        assert_eq!(BLUE, Some(Color::Blue));
+        assert_eq!(Color::from_index(10), Some(Color::Red));
+        assert_eq!(Color::from_index(11), Some(Color::Green));
+        assert_eq!(Color::from_index(0), None);
+        assert_eq!(Derived::from_index(7), Some(Derived::B));
+    }
+
+    #[test]
+    fn test_from_index_skips_data_variants() {
+        assert_eq!(Shape::from_index(1), Some(Shape::Empty));
+        assert_eq!(Shape::from_index(2), None);
+        assert_eq!(Shape::from_index(3), None);
+        assert_eq!(Wrapper::<String>::from_index(1), Some(Wrapper::Empty));
+        assert_eq!(Wrapper::<String>::from_index(2), None);
+    }
+
+    #[test]
+    fn test_const_data_variants() {
+        const CIRCLE: Shape = Shape::Circle(4);
+        const CIRCLE_VALUE: &str = CIRCLE.value();
+        assert_eq!(CIRCLE_VALUE, "circle");
+        assert_eq!(Shape::Rect { w: 1, h: 2 }.index(), 3);
+    }
+}
@@ -1,3 +1,6 @@
+// Exercises the deprecated Display inferred from a derived `Debug`
+#![allow(deprecated)]
+
 #[e_macros::value]
 #[derive(Debug, PartialEq, Default)]
 #[repr(u8)]
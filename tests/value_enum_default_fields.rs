@@ -0,0 +1,81 @@
+#![allow(dead_code)]
+
+use e_macros::ErrorKind;
+use std::str::FromStr;
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+enum ApiStatus {
+    #[e(value = "OK", index = 200)]
+    Ok,
+    #[e(value = "NOT_FOUND", index = 404, alias = "MISSING", default_fields)]
+    NotFound(String),
+    #[e(value = "MOVED", index = 301, default_fields)]
+    Moved { location: String, permanent: bool },
+    #[e(value = "ERROR", index = 500)]
+    Error(String),
+}
+
+#[e_macros::value(default_fields, display)]
+#[derive(Debug, PartialEq)]
+enum Slot<T> {
+    #[e(value = "empty")]
+    Empty,
+    #[e(value = "full")]
+    Full(T),
+}
+
+struct NoDefault;
+
+#[derive(Debug, PartialEq, e_macros::Value)]
+#[e(default_fields)]
+enum Derived {
+    #[e(value = "count")]
+    Count(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variant_default_fields() {
+        assert_eq!(ApiStatus::try_from(404), Ok(ApiStatus::NotFound(String::new())));
+        assert_eq!(
+            ApiStatus::try_from("NOT_FOUND"),
+            Ok(ApiStatus::NotFound(String::new()))
+        );
+        assert_eq!(
+            ApiStatus::try_from("MISSING"),
+            Ok(ApiStatus::NotFound(String::new()))
+        );
+        assert_eq!(
+            ApiStatus::from_str("MOVED"),
+            Ok(ApiStatus::Moved {
+                location: String::new(),
+                permanent: false
+            })
+        );
+    }
+
+    #[test]
+    fn test_without_default_fields() {
+        let err = ApiStatus::try_from(500).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RequiresData { variant: "Error" });
+        assert_eq!(
+            err.to_string(),
+            r#"index 500 of enum "ApiStatus" maps to Error, which requires data"#
+        );
+        assert!(ApiStatus::try_from("ERROR").is_err());
+    }
+
+    #[test]
+    fn test_container_default_fields() {
+        assert_eq!(Slot::<u8>::try_from("full"), Ok(Slot::Full(0)));
+        assert_eq!(Slot::<String>::try_from(2), Ok(Slot::Full(String::new())));
+        assert_eq!(Derived::try_from("count"), Ok(Derived::Count(0)));
+        // The enum stays usable with payloads that have no default
+        let slot: Slot<NoDefault> = Slot::Empty;
+        assert_eq!(slot.value(), "empty");
+    }
+}
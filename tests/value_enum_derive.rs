@@ -0,0 +1,60 @@
+#![allow(dead_code)]
+
+use e_macros::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Value)]
+#[e(display)]
+#[repr(u8)]
+enum Color {
+    #[e(value = "RED", index = 0)]
+    Red,
+    #[e(value = "GREEN")]
+    Green,
+    Blue,
+}
+
+#[derive(Debug, PartialEq, Value)]
+#[e(display)]
+enum Shape<T> {
+    #[e(value = "point")]
+    Point,
+    #[e(value = "circle", index = 10)]
+    Circle(T),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_and_index() {
+        assert_eq!(Color::Red.value(), "RED");
+        assert_eq!(Color::Blue.value(), "Blue");
+        assert_eq!(Color::Green.index(), 1u8);
+        assert_eq!(Color::Blue.index(), 2u8);
+        assert_eq!(Color::variant_count(), 3);
+        assert_eq!(Shape::Circle(1.5).index(), 10);
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(Color::try_from("GREEN"), Ok(Color::Green));
+        assert_eq!(Color::try_from(2u8), Ok(Color::Blue));
+        assert!(Color::try_from("Red").is_err());
+        assert_eq!(Shape::<u8>::try_from("point"), Ok(Shape::Point));
+        assert!(Shape::<u8>::try_from("circle").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Color::Green.to_string(), "GREEN");
+        assert_eq!(Shape::Circle(2).to_string(), "circle");
+    }
+
+    #[test]
+    fn test_enum_is_untouched() {
+        // The derive keeps the enum's own layout and discriminants
+        assert_eq!(std::mem::size_of::<Color>(), 1);
+        assert_eq!(Color::Blue as u8, 2);
+    }
+}
@@ -0,0 +1,145 @@
+#![allow(dead_code)]
+
+#[e_macros::value(display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(i8)]
+enum Level {
+    Low = -2,
+    Mid,
+    High = 100,
+    Max,
+    #[e(index = 127)]
+    Top = 127,
+}
+
+const BASE: u16 = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq, e_macros::Value)]
+#[repr(u16)]
+enum Port {
+    Http = BASE * 2,
+    Next,
+}
+
+#[e_macros::value(allow_index_override, display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Flag {
+    #[e(index = 10)]
+    Read = 1,
+    Write = 2,
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Partial {
+    A,
+    B = 1,
+    C,
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Color {
+    Red,
+    Green = 10,
+    #[e(index = 20)]
+    Blue,
+    Black,
+}
+
+const SHIFT: u8 = 4;
+
+#[e_macros::value(display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Shifted {
+    #[e(index = 8)]
+    Low = SHIFT * 2,
+    High,
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+enum Ffi {
+    A,
+    B = 5,
+    C,
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(usize)]
+enum Slot {
+    A,
+    B,
+    C = 8,
+    D,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discriminant_is_index() {
+        for level in Level::iter() {
+            assert_eq!(level.index(), level as i8);
+        }
+        assert_eq!(Level::INDICES, [-2, -1, 100, 101, 127]);
+        assert_eq!(Level::try_from(101i8), Ok(Level::Max));
+    }
+
+    #[test]
+    fn test_non_literal_discriminant() {
+        assert_eq!(Port::Http.index(), 80);
+        assert_eq!(Port::Next.index(), 81);
+        assert_eq!(Port::Next.index(), Port::Next as u16);
+    }
+
+    #[test]
+    fn test_index_override() {
+        assert_eq!(Flag::Read.index(), 10);
+        assert_eq!(Flag::Read as u8, 1);
+        assert_eq!(Flag::Write.index(), 2);
+        assert_eq!(Flag::try_from(10u8), Ok(Flag::Read));
+    }
+
+    #[test]
+    fn test_leading_implicit_variants() {
+        assert_eq!(Partial::INDICES, [0, 1, 2]);
+        for partial in Partial::iter() {
+            assert_eq!(partial.index(), partial as i32);
+        }
+
+        assert_eq!(Color::Red.index(), Color::Red as u8);
+        assert_eq!(Color::from_index(0), Some(Color::Red));
+        // An `#[e(index)]` does not shift the discriminants of the following variants
+        assert_eq!(Color::INDICES, [0, 10, 20, 12]);
+        assert_eq!(Color::Black.index(), Color::Black as u8);
+    }
+
+    #[test]
+    fn test_leading_implicit_variants_without_folding() {
+        // `c_int` and `usize` indices are counted by the compiler
+        assert_eq!(Ffi::INDICES, [0, 5, 6]);
+        for ffi in Ffi::iter() {
+            assert_eq!(ffi.index(), ffi as std::ffi::c_int);
+        }
+        assert_eq!(Ffi::from_index(0), Some(Ffi::A));
+
+        assert_eq!(Slot::INDICES, [0, 1, 8, 9]);
+        for slot in Slot::iter() {
+            assert_eq!(slot.index(), slot as usize);
+        }
+        assert_eq!(Slot::try_from(1usize), Ok(Slot::B));
+    }
+
+    #[test]
+    fn test_non_literal_index_and_discriminant() {
+        assert_eq!(Shifted::Low.index(), 8);
+        assert_eq!(Shifted::High.index(), Shifted::High as u8);
+    }
+}
@@ -0,0 +1,158 @@
+#![allow(dead_code)]
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(u16)]
+enum ApiStatus {
+    #[e(value = "OK", index = 200)]
+    Ok,
+    #[e(value = "NOT_FOUND", index = 404, display = "not found: {0}")]
+    NotFound(String),
+    #[e(value = "ERROR", index = 500, display = "error {message} (code {index})")]
+    Error { message: String, retry: bool },
+    #[e(value = "MOVED", index = 301, display = "{value} -> {1:>6}|{0:?}|{{literal}}")]
+    Moved(u8, String),
+}
+
+#[derive(Debug, e_macros::Value)]
+enum Field {
+    #[e(display = "{value}={index}, again {value}")]
+    Plain,
+    #[e(display = "{index} is a field here")]
+    Shadowed { index: &'static str },
+    #[e(display = "{f}")]
+    Formatter { f: u8 },
+}
+
+#[e_macros::value]
+enum NoDebug {
+    #[e(display = "templated {value}")]
+    Templated,
+    Plain,
+}
+
+#[e_macros::value(display = "index")]
+#[derive(Debug, PartialEq)]
+#[repr(u16)]
+enum ByIndex {
+    #[e(index = 200)]
+    Ok,
+    #[e(index = 404, display = "missing {value}")]
+    NotFound,
+}
+
+#[e_macros::value(display = "name")]
+enum ByName {
+    #[e(value = "r")]
+    Red,
+    #[e(value = "g")]
+    Green(u8),
+    #[e(value = "b")]
+    r#Blue { level: u8 },
+}
+
+#[e_macros::value(no_display, rename_all = "snake_case")]
+#[derive(Debug)]
+enum HandWritten {
+    DarkRed,
+}
+
+impl std::fmt::Display for HandWritten {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}>", self.value())
+    }
+}
+
+#[derive(Debug, e_macros::Value)]
+#[e(display = "name")]
+enum DerivedByName {
+    #[e(value = "x")]
+    X,
+}
+
+#[derive(Debug, e_macros::Value)]
+#[e(no_display)]
+enum DerivedHandWritten {
+    #[e(value = "y")]
+    Y,
+}
+
+impl std::fmt::Display for DerivedHandWritten {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("hand written")
+    }
+}
+
+// Without `display` the derive leaves `Display` to the enum, even though it derives `Debug`
+#[derive(Debug, e_macros::Value)]
+enum DerivedPlain {
+    #[e(value = "z")]
+    Z,
+}
+
+impl std::fmt::Display for DerivedPlain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "plain {}", self.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_templates() {
+        assert_eq!(ApiStatus::NotFound("x".into()).to_string(), "not found: x");
+        let error = ApiStatus::Error {
+            message: "boom".into(),
+            retry: false,
+        };
+        assert_eq!(error.to_string(), "error boom (code 500)");
+        assert_eq!(
+            ApiStatus::Moved(7, "home".into()).to_string(),
+            "MOVED ->   home|7|{literal}"
+        );
+    }
+
+    #[test]
+    fn test_builtins_and_shadowing() {
+        assert_eq!(Field::Plain.to_string(), "Plain=1, again Plain");
+        assert_eq!(
+            Field::Shadowed { index: "idx" }.to_string(),
+            "idx is a field here"
+        );
+        assert_eq!(Field::Formatter { f: 3 }.to_string(), "3");
+    }
+
+    #[test]
+    fn test_padding_without_template() {
+        assert_eq!(format!("{:>5}|", ApiStatus::Ok), "   OK|");
+        assert_eq!(format!("{:*<6}|", ApiStatus::Ok), "OK****|");
+        assert_eq!(format!("{:^6}|", ApiStatus::Ok), "  OK  |");
+        assert_eq!(format!("{:.1}", ApiStatus::Ok), "O");
+    }
+
+    #[test]
+    fn test_template_implies_display() {
+        assert_eq!(NoDebug::Templated.to_string(), "templated Templated");
+        assert_eq!(NoDebug::Plain.to_string(), "Plain");
+    }
+
+    #[test]
+    fn test_display_modes() {
+        assert_eq!(ByIndex::Ok.to_string(), "200");
+        assert_eq!(format!("{:>5}|", ByIndex::Ok), "  200|");
+        assert_eq!(ByIndex::NotFound.to_string(), "missing NotFound");
+        assert_eq!(ByName::Red.to_string(), "Red");
+        assert_eq!(ByName::Green(1).to_string(), "Green");
+        assert_eq!(format!("{:-<6}", ByName::Blue { level: 0 }), "Blue--");
+        assert_eq!(DerivedByName::X.to_string(), "X");
+    }
+
+    #[test]
+    fn test_no_display() {
+        assert_eq!(HandWritten::DarkRed.to_string(), "<dark_red>");
+        assert_eq!(DerivedHandWritten::Y.to_string(), "hand written");
+        assert_eq!(DerivedPlain::Z.to_string(), "plain z");
+    }
+}
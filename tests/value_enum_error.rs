@@ -0,0 +1,102 @@
+#![allow(dead_code)]
+
+use e_macros::{Error, ErrorKind, Input};
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(u16)]
+enum ApiStatus {
+    #[e(value = "OK", index = 200)]
+    Ok,
+    #[e(value = "NOT_FOUND", index = 404)]
+    NotFound(String),
+    #[e(value = "SERVER_ERROR", index = 500)]
+    ServerError { message: String },
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(i8)]
+enum Level {
+    #[e(index = -1)]
+    Low,
+    High,
+}
+
+#[e_macros::value(no_display)]
+#[derive(Debug, PartialEq)]
+enum r#Kw {
+    r#Fn,
+    r#Type(u8),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_value() {
+        let err = ApiStatus::try_from("MOVED").unwrap_err();
+        assert_eq!(err.enum_name(), "ApiStatus");
+        assert_eq!(err.input(), &Input::Str);
+        assert_eq!(err.value(), Some("MOVED"));
+        assert_eq!(err.kind(), ErrorKind::UnknownValue);
+        assert_eq!(err, Error::unknown_value("ApiStatus", "MOVED"));
+        assert_eq!(
+            err.to_string(),
+            r#"Invalid string value "MOVED" for enum "ApiStatus""#
+        );
+    }
+
+    #[test]
+    fn test_unknown_index() {
+        let err = ApiStatus::try_from(301u16).unwrap_err();
+        assert_eq!(err.input(), &Input::UInt(301));
+        assert_eq!(err.value(), None);
+        assert_eq!(err.kind(), ErrorKind::UnknownIndex);
+        assert_eq!(err.to_string(), r#"Invalid value 301 for enum "ApiStatus""#);
+
+        let err = Level::try_from(-2i8).unwrap_err();
+        assert_eq!(err.input(), &Input::Int(-2));
+        assert_eq!(err.to_string(), r#"Invalid value -2 for enum "Level""#);
+    }
+
+    #[test]
+    fn test_requires_data() {
+        let err = ApiStatus::try_from(404u16).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RequiresData { variant: "NotFound" });
+        assert_eq!(
+            err.to_string(),
+            r#"index 404 of enum "ApiStatus" maps to NotFound, which requires data"#
+        );
+
+        let err = ApiStatus::try_from("SERVER_ERROR").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RequiresData { variant: "ServerError" });
+        assert_eq!(err.value(), Some("SERVER_ERROR"));
+        assert_eq!(
+            err.to_string(),
+            r#"value "SERVER_ERROR" of enum "ApiStatus" maps to ServerError, which requires data"#
+        );
+    }
+
+    #[test]
+    fn test_raw_identifiers() {
+        let err = Kw::try_from(2).unwrap_err();
+        assert_eq!(err.enum_name(), "Kw");
+        assert_eq!(err.kind(), ErrorKind::RequiresData { variant: "Type" });
+        assert_eq!(
+            err.to_string(),
+            r#"index 2 of enum "Kw" maps to Type, which requires data"#
+        );
+        assert_eq!(Kw::try_from("Fn").unwrap(), Kw::Fn);
+    }
+
+    #[test]
+    fn test_std_error() {
+        fn parse(value: &str) -> Result<Level, Box<dyn std::error::Error>> {
+            Ok(Level::try_from(value)?)
+        }
+        assert_eq!(parse("High").unwrap(), Level::High);
+        assert!(parse("Medium").is_err());
+    }
+}
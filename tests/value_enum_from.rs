@@ -1,4 +1,4 @@
-#[e_macros::value]
+#[e_macros::value(display)]
 #[derive(Debug, PartialEq, Default)]
 #[repr(u8)]
 enum TestEnum {
@@ -9,6 +9,7 @@ enum TestEnum {
     Two,
     #[e(index = 255)]
     Three,
+    #[e(index = 100)]
     Custom(i32),
 }
 
@@ -29,7 +30,7 @@ mod tests {
         assert_eq!(TestEnum::One.index(), 1);
         assert_eq!(TestEnum::Two.index(), 20);
         assert_eq!(TestEnum::Three.index(), 255);
-        assert_eq!(TestEnum::Custom(42).index(), 255);
+        assert_eq!(TestEnum::Custom(42).index(), 100);
     }
 
     #[test]
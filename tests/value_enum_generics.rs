@@ -0,0 +1,75 @@
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+trait Payload: Debug {}
+
+#[derive(Debug, PartialEq)]
+struct Message(&'static str);
+
+impl Payload for Message {}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(u8)]
+enum Event<'a, T: Payload> {
+    #[e(value = "msg", index = 1)]
+    Msg(&'a T),
+    #[e(value = "ping")]
+    Ping,
+    #[e(value = "close")]
+    Close { reason: Option<&'a T> },
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Wrapper<T>
+where
+    T: Clone,
+{
+    #[e(value = "empty")]
+    Empty,
+    #[e(value = "item")]
+    Item(T),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_value_and_index() {
+        let message = Message("hi");
+        let msg = Event::Msg(&message);
+        assert_eq!(msg.value(), "msg");
+        assert_eq!(msg.index(), 1);
+        assert_eq!(Event::<Message>::Ping.index(), 2);
+        let close: Event<Message> = Event::Close { reason: None };
+        assert_eq!(close.value(), "close");
+        assert_eq!(Event::<Message>::variant_count(), 3);
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(Event::<Message>::try_from("ping"), Ok(Event::Ping));
+        assert_eq!(Event::<Message>::try_from(2u8), Ok(Event::Ping));
+        assert!(Event::<Message>::try_from("msg").is_err());
+        assert!(Event::<Message>::try_from(1u8).is_err());
+        assert_eq!(Wrapper::<u32>::try_from("empty"), Ok(Wrapper::Empty));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Event::<Message>::Ping.to_string(), "ping");
+        assert_eq!(Wrapper::Item(5).to_string(), "item");
+    }
+
+    #[test]
+    fn test_serde() {
+        let item = Wrapper::Item(7u32);
+        assert_eq!(item.to_serde().unwrap(), r#"{"Item":7}"#);
+        assert_eq!(Wrapper::<u32>::from_serde(json!({"Item": 7})).unwrap(), item);
+    }
+}
@@ -0,0 +1,92 @@
+#![allow(dead_code)]
+
+// Generated code must not depend on the prelude or on names the surrounding module may shadow
+mod shadowed {
+    #![no_implicit_prelude]
+
+    pub type Result<T> = ::core::result::Result<T, ()>;
+    pub struct Error;
+    pub trait TryFrom<T> {}
+    pub trait From<T> {}
+    pub trait Default {}
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Ok;
+    pub struct Err;
+    pub struct String;
+    pub trait Iterator {}
+    pub trait IntoIterator {}
+    #[allow(unused_macros)]
+    macro_rules! write {
+        ($($tt:tt)*) => {
+            compile_error!("the local `write!` was used")
+        };
+    }
+
+    #[::e_macros::value(parse(case_insensitive), default_fields, serde = "lenient")]
+    #[derive(::core::fmt::Debug, ::core::cmp::PartialEq)]
+    #[repr(u8)]
+    pub enum Color {
+        #[e(value = "red", alias = "r")]
+        Red,
+        #[e(value = "green", display = "green #{index}")]
+        Green,
+        #[e(value = "rgb", pattern = "rgb({0},{1},{2})")]
+        Rgb(u8, u8, u8),
+        #[e(value = "named")]
+        Named { name: ::std::string::String },
+        #[e(other)]
+        Unknown(u8),
+    }
+
+    #[::e_macros::value]
+    #[derive(::core::fmt::Debug, ::core::cmp::PartialEq)]
+    pub enum Label {
+        #[e(value = "a")]
+        A,
+        #[e(other)]
+        Other(::std::string::String),
+    }
+}
+
+// A crate re-exporting e-macros under another path
+mod runtime {
+    pub use e_macros::*;
+}
+
+#[e_macros::value(crate = "crate::runtime", display)]
+#[derive(Debug, PartialEq)]
+enum Redirected {
+    #[e(value = "one")]
+    One,
+    #[e(value = "two", pattern = "two:{0}")]
+    Two(u8),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::shadowed::{Color, Label};
+    use super::Redirected;
+
+    #[test]
+    fn test_shadowed_names() {
+        assert_eq!(Color::try_from("R"), Ok(Color::Red));
+        assert_eq!(Color::try_from("rgb(1,2,3)"), Ok(Color::Rgb(1, 2, 3)));
+        assert_eq!(Color::from(1), Color::Red);
+        assert_eq!(Color::from(200), Color::Unknown(200));
+        assert_eq!(Color::Green.to_string(), "green #2");
+        assert_eq!(Color::iter().count(), 5);
+        assert_eq!(serde_json::to_string(&Color::Red).unwrap(), r#""red""#);
+        assert_eq!(serde_json::from_str::<Color>("2").unwrap(), Color::Green);
+        assert_eq!(Label::from("b"), Label::Other("b".to_string()));
+    }
+
+    #[test]
+    fn test_crate_path() {
+        assert_eq!(Redirected::try_from("two:7"), Ok(Redirected::Two(7)));
+        let err = Redirected::try_from("three").unwrap_err();
+        assert_eq!(err.kind(), crate::runtime::ErrorKind::UnknownValue);
+        assert!(Redirected::PARSE_POLICY == e_macros::ParsePolicy::default());
+    }
+}
@@ -0,0 +1,63 @@
+#![allow(dead_code)]
+
+const BASE: u16 = 1000;
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(u16)]
+enum Code {
+    #[e(index = BASE)]
+    First,
+    Second,
+    Third(String),
+    #[e(index = 7)]
+    Seventh,
+    Eighth,
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(usize)]
+enum Slot {
+    Zero,
+    #[e(index = 41)]
+    Answer,
+    Next,
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(i8)]
+enum Edge {
+    #[e(index = 126)]
+    A,
+    B,
+    #[e(index = -128)]
+    Min,
+    AfterMin,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_base() {
+        assert_eq!(Code::INDICES, [1000, 1001, 1002, 7, 8]);
+        assert_eq!(Code::Second.index(), 1001);
+        assert_eq!(Code::try_from(1001u16), Ok(Code::Second));
+        assert_eq!(Code::from_index(8), Some(Code::Eighth));
+    }
+
+    #[test]
+    fn test_target_dependent_repr() {
+        assert_eq!(Slot::INDICES, [1, 41, 42]);
+        assert_eq!(Slot::from_index(42), Some(Slot::Next));
+    }
+
+    #[test]
+    fn test_range_edges() {
+        assert_eq!(Edge::INDICES, [126, 127, -128, -127]);
+        assert_eq!(Edge::try_from(127i8), Ok(Edge::B));
+    }
+}
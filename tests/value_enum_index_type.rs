@@ -0,0 +1,70 @@
+#![allow(dead_code)]
+
+use std::mem::size_of;
+
+#[e_macros::value(index_type = u16, display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum HttpStatus {
+    #[e(value = "OK", index = 200)]
+    Ok,
+    #[e(value = "NOT_FOUND", index = 404)]
+    NotFound,
+    #[e(value = "GONE")]
+    Gone,
+}
+
+#[derive(Debug, PartialEq, e_macros::Value)]
+#[e(index_type = i64)]
+#[repr(u8)]
+enum Offset {
+    #[e(index = -5_000_000_000)]
+    Back,
+    Forward,
+}
+
+#[e_macros::value(index_type = u16, display)]
+#[derive(Debug, PartialEq)]
+enum Payload {
+    #[e(index = 1000)]
+    Empty,
+    Data(u8),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_keeps_repr() {
+        assert_eq!(size_of::<HttpStatus>(), 1);
+        assert_eq!(size_of::<Offset>(), 1);
+    }
+
+    #[test]
+    fn test_index_uses_index_type() {
+        let index: u16 = HttpStatus::NotFound.index();
+        assert_eq!(index, 404);
+        assert_eq!(HttpStatus::Gone.index(), 405);
+        assert_eq!(Offset::Forward.index(), -4_999_999_999i64);
+        assert_eq!(Payload::Data(1).index(), 1001);
+    }
+
+    #[test]
+    fn test_try_from_index_type() {
+        assert_eq!(HttpStatus::try_from(404u16), Ok(HttpStatus::NotFound));
+        assert!(HttpStatus::try_from(500u16).is_err());
+        assert_eq!(Offset::try_from(-5_000_000_000i64), Ok(Offset::Back));
+        assert_eq!(HttpStatus::from_index(200), Some(HttpStatus::Ok));
+    }
+
+    #[test]
+    fn test_tables_and_traits_use_index_type() {
+        let indices: [u16; 3] = HttpStatus::INDICES;
+        assert_eq!(indices, [200, 404, 405]);
+        assert_eq!(u16::from(HttpStatus::Gone), 405);
+        assert_eq!(HttpStatus::NotFound, 404u16);
+        // The enum discriminants are untouched
+        assert_eq!(HttpStatus::NotFound as u8, 1);
+    }
+}
@@ -0,0 +1,123 @@
+#![allow(dead_code)]
+
+#[e_macros::value(display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Color {
+    #[e(value = "RED", index = 10)]
+    Red,
+    #[e(value = "GREEN")]
+    Green,
+    #[e(value = "BLUE", index = 3)]
+    Blue,
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+enum Shape {
+    #[e(value = "empty")]
+    Empty,
+    #[e(value = "circle")]
+    Circle(u32),
+    #[e(value = "rect")]
+    Rect { w: u32, label: String },
+}
+
+struct NoDefault;
+
+// Payloads without `Default` only make `iter()` unavailable; the tables are still generated
+#[e_macros::value]
+enum Holder<'a> {
+    #[e(value = "none")]
+    Nothing,
+    #[e(value = "some")]
+    Something(&'a NoDefault),
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+enum Wrapper<T> {
+    #[e(value = "empty")]
+    Empty,
+    #[e(value = "item")]
+    Item(T),
+}
+
+#[derive(Debug, PartialEq, e_macros::Value)]
+enum Derived {
+    #[e(value = "a")]
+    A,
+    #[e(value = "b", index = 7)]
+    B,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_tables() {
+        assert_eq!(Color::VARIANTS, [Color::Red, Color::Green, Color::Blue]);
+        assert_eq!(Color::VALUES, ["RED", "GREEN", "BLUE"]);
+        assert_eq!(Color::INDICES, [10u8, 11, 3]);
+        assert_eq!(Derived::VARIANTS, [Derived::A, Derived::B]);
+        assert_eq!(Derived::INDICES, [1, 7]);
+    }
+
+    #[test]
+    fn test_tables_match_accessors() {
+        for (i, color) in Color::iter().enumerate() {
+            assert_eq!(color.value(), Color::VALUES[i]);
+            assert_eq!(color.index(), Color::INDICES[i]);
+        }
+        assert_eq!(Color::VARIANTS.len(), Color::variant_count());
+    }
+
+    #[test]
+    fn test_tables_are_const() {
+        const FIRST: &str = Color::VALUES[0];
+        const LAST: u8 = Color::INDICES[2];
+        const GREEN: Color = Color::VARIANTS[1];
+        assert_eq!(FIRST, "RED");
+        assert_eq!(LAST, 3);
+        assert_eq!(GREEN, Color::Green);
+    }
+
+    #[test]
+    fn test_iter_unit() {
+        let colors: Vec<Color> = Color::iter().collect();
+        assert_eq!(colors, Color::VARIANTS);
+        assert_eq!(Color::iter().len(), 3);
+        assert_eq!(Color::iter().next_back(), Some(Color::Blue));
+    }
+
+    #[test]
+    fn test_iter_default_payloads() {
+        let shapes: Vec<Shape> = Shape::iter().collect();
+        assert_eq!(
+            shapes,
+            [
+                Shape::Empty,
+                Shape::Circle(0),
+                Shape::Rect {
+                    w: 0,
+                    label: String::new()
+                },
+            ]
+        );
+        assert_eq!(Shape::VALUES, ["empty", "circle", "rect"]);
+        assert_eq!(Shape::INDICES, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_tables_without_default() {
+        assert_eq!(Holder::VALUES, ["none", "some"]);
+        assert_eq!(Holder::INDICES, [1, 2]);
+    }
+
+    #[test]
+    fn test_iter_generic() {
+        let items: Vec<Wrapper<String>> = Wrapper::iter().collect();
+        assert_eq!(items, [Wrapper::Empty, Wrapper::Item(String::new())]);
+    }
+}
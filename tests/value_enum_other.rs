@@ -0,0 +1,102 @@
+#![allow(dead_code, clippy::unnecessary_fallible_conversions)]
+
+use std::convert::Infallible;
+use std::str::FromStr;
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(u8)]
+enum Command {
+    #[e(value = "GET", index = 1)]
+    Get,
+    #[e(value = "SET", index = 2)]
+    Set(String),
+    #[e(value = "PUT", alias = "put")]
+    Put,
+    #[e(other)]
+    Unknown(String),
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+enum Opcode {
+    #[e(value = "NOP", index = 0)]
+    Nop,
+    #[e(value = "JMP")]
+    Jmp,
+    #[e(value = "RAW", other)]
+    Raw(u16),
+}
+
+#[derive(Debug, PartialEq, e_macros::Value)]
+#[e(parse(case_insensitive))]
+enum Both {
+    #[e(value = "known", index = 10)]
+    Known,
+    #[e(other)]
+    Name(String),
+    #[e(other)]
+    Code(i32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_string_is_captured() {
+        assert_eq!(Command::from("GET"), Command::Get);
+        assert_eq!(Command::from("put"), Command::Put);
+        assert_eq!(Command::from("DELETE"), Command::Unknown("DELETE".to_string()));
+        // Known values that cannot be converted are captured as well
+        assert_eq!(Command::from("SET"), Command::Unknown("SET".to_string()));
+        let parsed: Result<Command, Infallible> = Command::try_from("PATCH");
+        assert_eq!(parsed, Ok(Command::Unknown("PATCH".to_string())));
+        assert_eq!(Command::from_str("GET"), Ok(Command::Get));
+    }
+
+    #[test]
+    fn test_captured_value() {
+        let unknown = Command::from("DELETE");
+        assert_eq!(unknown.value(), "DELETE");
+        assert_eq!(unknown.to_string(), "DELETE");
+        assert_eq!(unknown, "DELETE");
+        let value: &str = (&unknown).into();
+        assert_eq!(value, "DELETE");
+        assert_eq!(Command::Get.value(), "GET");
+        // Index conversion stays fallible without an index catch-all
+        assert_eq!(Command::Unknown(String::new()).index(), 4);
+        assert!(Command::try_from(9u8).is_err());
+    }
+
+    #[test]
+    fn test_unknown_index_is_captured() {
+        assert_eq!(Opcode::from(0u16), Opcode::Nop);
+        assert_eq!(Opcode::from(1u16), Opcode::Jmp);
+        assert_eq!(Opcode::from(77u16), Opcode::Raw(77));
+        assert_eq!(Opcode::from(77u16).index(), 77);
+        assert_eq!(Opcode::from_index(77), Some(Opcode::Raw(77)));
+        let parsed: Result<Opcode, Infallible> = Opcode::try_from(5u16);
+        assert_eq!(parsed, Ok(Opcode::Raw(5)));
+        // String conversion stays fallible without a string catch-all
+        assert!(Opcode::try_from("HALT").is_err());
+        assert_eq!(Opcode::Raw(3).value(), "RAW");
+    }
+
+    #[test]
+    fn test_const_index_with_capture() {
+        const RAW: u16 = Opcode::Raw(9).index();
+        assert_eq!(RAW, 9);
+    }
+
+    #[test]
+    fn test_string_and_index_catch_alls() {
+        assert_eq!(Both::from("KNOWN"), Both::Known);
+        assert_eq!(Both::from("other"), Both::Name("other".to_string()));
+        assert_eq!(Both::from(10), Both::Known);
+        assert_eq!(Both::from(-3), Both::Code(-3));
+        assert_eq!(Both::Code(-3).index(), -3);
+        assert_eq!(Both::Name("x".to_string()).value(), "x");
+    }
+}
@@ -0,0 +1,107 @@
+#![allow(dead_code)]
+
+use e_macros::ParsePolicy;
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+enum Exact {
+    #[e(value = "RED")]
+    Red,
+}
+
+#[e_macros::value(parse(trim), display)]
+#[derive(Debug, PartialEq)]
+enum Trimmed {
+    #[e(value = "RED")]
+    Red,
+}
+
+#[e_macros::value(parse(case_insensitive), display)]
+#[derive(Debug, PartialEq)]
+enum Header {
+    #[e(value = "Content-Type")]
+    ContentType,
+    #[e(value = "Straße")]
+    Street,
+}
+
+#[e_macros::value(parse(case_insensitive, trim, ignore_separators), display)]
+#[derive(Debug, PartialEq)]
+enum Level {
+    #[e(value = "LOG_LEVEL_DEBUG")]
+    Debug,
+    #[e(value = "log-level-info")]
+    Info,
+    Data(u8),
+}
+
+#[derive(Debug, PartialEq, e_macros::Value)]
+#[e(parse(ignore_separators))]
+enum Mode {
+    #[e(value = "read_only")]
+    ReadOnly,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use e_macros::ErrorKind;
+
+    #[test]
+    fn test_policy_const() {
+        assert_eq!(Exact::PARSE_POLICY, ParsePolicy::EXACT);
+        assert!(Exact::PARSE_POLICY.is_exact());
+        assert_eq!(
+            Level::PARSE_POLICY,
+            ParsePolicy {
+                case_insensitive: true,
+                trim: true,
+                ignore_separators: true,
+            }
+        );
+        assert_eq!(Mode::PARSE_POLICY.trim, Exact::PARSE_POLICY.trim);
+        assert_ne!(Mode::PARSE_POLICY, ParsePolicy::EXACT);
+    }
+
+    #[test]
+    fn test_exact() {
+        assert_eq!(Exact::try_from("RED"), Ok(Exact::Red));
+        assert!(Exact::try_from("red").is_err());
+        assert!(Exact::try_from(" RED").is_err());
+    }
+
+    #[test]
+    fn test_trim() {
+        assert_eq!(Trimmed::try_from("  RED\t"), Ok(Trimmed::Red));
+        assert!(Trimmed::try_from("red").is_err());
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_eq!(Header::try_from("content-type"), Ok(Header::ContentType));
+        assert_eq!(Header::try_from("CONTENT-TYPE"), Ok(Header::ContentType));
+        assert_eq!(Header::try_from("STRASSE").ok(), None);
+        assert_eq!(Header::try_from("straße"), Ok(Header::Street));
+        assert!(Header::try_from("content_type").is_err());
+    }
+
+    #[test]
+    fn test_all() {
+        assert_eq!(Level::try_from(" log-level-debug "), Ok(Level::Debug));
+        assert_eq!(Level::try_from("LogLevelInfo"), Ok(Level::Info));
+        assert_eq!(Level::try_from("LOG_LEVEL_INFO"), Ok(Level::Info));
+        assert_eq!(
+            Level::try_from("data").unwrap_err().kind(),
+            ErrorKind::RequiresData { variant: "Data" }
+        );
+        let err = Level::try_from(" warn ").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid string value " warn " for enum "Level""#);
+    }
+
+    #[test]
+    fn test_derive() {
+        assert_eq!(Mode::try_from("readonly"), Ok(Mode::ReadOnly));
+        assert_eq!(Mode::try_from("read-only"), Ok(Mode::ReadOnly));
+        assert!(Mode::try_from("READ_ONLY").is_err());
+    }
+}
@@ -0,0 +1,159 @@
+#![allow(dead_code)]
+
+use e_macros::ErrorKind;
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+enum Status {
+    #[e(value = "OK")]
+    Ok,
+    #[e(value = "NOT_FOUND", pattern = "{value}:{0}")]
+    NotFound(String),
+    #[e(value = "V3", pattern = "V3({0},{1})")]
+    V3(bool, f64),
+    #[e(value = "range", pattern = "[{start}..{end}]")]
+    Range { start: u32, end: u32 },
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+enum Lenient {
+    #[e(value = "point", pattern = "point({0}, {1})")]
+    Point(i32, i32),
+    #[e(other)]
+    Other(String),
+}
+
+#[e_macros::value(parse(trim), display)]
+#[derive(Debug, PartialEq)]
+enum Trimmed {
+    #[e(value = "id", pattern = "#{0}")]
+    Id(u64),
+}
+
+#[e_macros::value(parse(case_insensitive, ignore_separators), display)]
+#[derive(Debug, PartialEq)]
+enum Relaxed {
+    #[e(value = "red")]
+    Red,
+    #[e(value = "rgb", pattern = "Rgb:{0}")]
+    Rgb(u8),
+    #[e(value = "hsl", pattern = "hsl_{0}_deg({1})")]
+    Hsl(u16, u8),
+    #[e(value = "temp", pattern = "temp={0}")]
+    Temp(i16),
+    #[e(value = "span", pattern = "span:{0}-to-{1}")]
+    Span(u8, u8),
+    #[e(value = "pair", pattern = "pair:{0}_x_{1}")]
+    Pair(String, String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pattern() {
+        assert_eq!(
+            Status::try_from("NOT_FOUND:/index.html"),
+            Ok(Status::NotFound("/index.html".to_string()))
+        );
+        assert_eq!("V3(true,1.5)".parse::<Status>(), Ok(Status::V3(true, 1.5)));
+        assert_eq!(
+            Status::try_from("[1..10]"),
+            Ok(Status::Range { start: 1, end: 10 })
+        );
+        assert_eq!(Status::try_from("OK"), Ok(Status::Ok));
+    }
+
+    #[test]
+    fn test_exact_value_still_requires_data() {
+        let err = Status::try_from("NOT_FOUND").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RequiresData { variant: "NotFound" });
+    }
+
+    #[test]
+    fn test_invalid_field() {
+        let err = Status::try_from("V3(yes,1.5)").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::InvalidField {
+                variant: "V3",
+                field: "0"
+            }
+        );
+        assert!(err.reason().is_some());
+        assert!(err.to_string().contains("field 0 is invalid"));
+
+        let err = Status::try_from("[1..x]").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::InvalidField {
+                variant: "Range",
+                field: "end"
+            }
+        );
+    }
+
+    #[test]
+    fn test_no_match() {
+        let err = Status::try_from("V3(true)").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnknownValue);
+        assert_eq!(err.reason(), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for status in [
+            Status::NotFound("/a".to_string()),
+            Status::V3(false, 2.25),
+            Status::Range { start: 3, end: 4 },
+        ] {
+            assert_eq!(Status::try_from(status.to_string().as_str()), Ok(status));
+        }
+        assert_eq!(Status::Ok.to_string(), "OK");
+        assert_eq!(Status::V3(true, 1.0).to_string(), "V3(true,1)");
+    }
+
+    #[test]
+    fn test_pattern_with_other() {
+        assert_eq!(Lenient::from("point(1, -2)"), Lenient::Point(1, -2));
+        assert_eq!(
+            Lenient::from("point(1, x)"),
+            Lenient::Other("point(1, x)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_pattern_trim() {
+        assert_eq!(Trimmed::try_from("  #42 "), Ok(Trimmed::Id(42)));
+    }
+
+    #[test]
+    fn test_pattern_policy() {
+        assert_eq!(Relaxed::try_from("RED"), Ok(Relaxed::Red));
+        assert_eq!(Relaxed::try_from("RGB:1"), Ok(Relaxed::Rgb(1)));
+        assert_eq!(Relaxed::try_from("rgb:2"), Ok(Relaxed::Rgb(2)));
+        assert_eq!(Relaxed::try_from("HSL-120-DEG(50)"), Ok(Relaxed::Hsl(120, 50)));
+        assert_eq!(Relaxed::try_from("hsl120deg(50)"), Ok(Relaxed::Hsl(120, 50)));
+        assert_eq!(Relaxed::try_from("hsl_120_deg(50)_"), Ok(Relaxed::Hsl(120, 50)));
+        assert_eq!(Relaxed::try_from("TEMP=-5"), Ok(Relaxed::Temp(-5)));
+        let err = Relaxed::try_from("RGB;1").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnknownValue);
+    }
+
+    #[test]
+    fn test_pattern_policy_round_trip() {
+        let variants = [
+            Relaxed::Rgb(7),
+            Relaxed::Hsl(120, 50),
+            Relaxed::Temp(-5),
+            Relaxed::Span(1, 2),
+            Relaxed::Pair("ab".into(), "cd".into()),
+        ];
+        for variant in variants {
+            assert_eq!(Relaxed::try_from(variant.to_string().as_str()), Ok(variant));
+        }
+        assert_eq!(Relaxed::try_from("SPAN:1TO2"), Ok(Relaxed::Span(1, 2)));
+    }
+}
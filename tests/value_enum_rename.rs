@@ -0,0 +1,84 @@
+#![allow(dead_code)]
+
+#[e_macros::value(rename_all = "SCREAMING_SNAKE_CASE", display)]
+#[derive(Debug, PartialEq)]
+enum ErrorCode {
+    NotFound,
+    HTTPVersionNotSupported,
+    #[e(value = "teapot")]
+    ImATeapot,
+    Status2xx(u16),
+}
+
+#[e_macros::value(rename_all = "kebab-case", prefix = "color-", suffix = ".v1", display)]
+#[derive(Debug, PartialEq)]
+enum Color {
+    DarkRed,
+    #[e(value = "BLUE")]
+    Blue,
+}
+
+#[derive(Debug, PartialEq, e_macros::Value)]
+#[e(rename_all = "camelCase")]
+enum Field {
+    UserName,
+    r#Type,
+}
+
+#[e_macros::value(prefix = "COLOR_", display)]
+#[derive(Debug, PartialEq)]
+enum Prefixed {
+    Red,
+}
+
+macro_rules! rename_case {
+    ($name:ident, $rule:literal, $expected:literal) => {
+        #[test]
+        fn $name() {
+            #[e_macros::value(rename_all = $rule)]
+            enum TestEnum {
+                HTTPStatusCode,
+            }
+            assert_eq!(TestEnum::HTTPStatusCode.value(), $expected);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    rename_case!(test_lowercase, "lowercase", "httpstatuscode");
+    rename_case!(test_uppercase, "UPPERCASE", "HTTPSTATUSCODE");
+    rename_case!(test_pascal_case, "PascalCase", "HTTPStatusCode");
+    rename_case!(test_camel_case, "camelCase", "httpStatusCode");
+    rename_case!(test_snake_case, "snake_case", "http_status_code");
+    rename_case!(test_screaming_snake_case, "SCREAMING_SNAKE_CASE", "HTTP_STATUS_CODE");
+    rename_case!(test_kebab_case, "kebab-case", "http-status-code");
+    rename_case!(test_screaming_kebab_case, "SCREAMING-KEBAB-CASE", "HTTP-STATUS-CODE");
+
+    #[test]
+    fn test_rename_all() {
+        assert_eq!(ErrorCode::NotFound.value(), "NOT_FOUND");
+        assert_eq!(ErrorCode::HTTPVersionNotSupported.value(), "HTTP_VERSION_NOT_SUPPORTED");
+        assert_eq!(ErrorCode::ImATeapot.value(), "teapot");
+        assert_eq!(ErrorCode::Status2xx(200).value(), "STATUS2XX");
+        assert_eq!(ErrorCode::try_from("NOT_FOUND"), Ok(ErrorCode::NotFound));
+        assert!(ErrorCode::try_from("NotFound").is_err());
+    }
+
+    #[test]
+    fn test_prefix_and_suffix() {
+        assert_eq!(Color::DarkRed.value(), "color-dark-red.v1");
+        assert_eq!(Color::Blue.value(), "BLUE");
+        assert_eq!(Color::try_from("color-dark-red.v1"), Ok(Color::DarkRed));
+        assert_eq!(Prefixed::Red.value(), "COLOR_Red");
+    }
+
+    #[test]
+    fn test_derive() {
+        assert_eq!(Field::UserName.value(), "userName");
+        assert_eq!(Field::Type.value(), "type");
+        assert_eq!(Field::try_from("type"), Ok(Field::Type));
+    }
+}
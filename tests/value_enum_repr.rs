@@ -1,3 +1,5 @@
+#![allow(clippy::enum_clike_unportable_variant)]
+
 use e_macros::value;
 
 #[value]
@@ -6,7 +8,7 @@ enum TestEnumNoDebug {
     One,
 }
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(i8)]
 enum TestEnumI8 {
@@ -14,7 +16,7 @@ enum TestEnumI8 {
     Two,
 }
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(u8)]
 enum TestEnumU8 {
@@ -22,7 +24,7 @@ enum TestEnumU8 {
     Two,
 }
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(i16)]
 enum TestEnumI16 {
@@ -30,7 +32,7 @@ enum TestEnumI16 {
     Two,
 }
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(u16)]
 enum TestEnumU16 {
@@ -38,7 +40,7 @@ enum TestEnumU16 {
     Two,
 }
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(i32)]
 enum TestEnumI32 {
@@ -46,7 +48,7 @@ enum TestEnumI32 {
     Two,
 }
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(u32)]
 enum TestEnumU32 {
@@ -54,7 +56,7 @@ enum TestEnumU32 {
     Two,
 }
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(i64)]
 enum TestEnumI64 {
@@ -62,7 +64,7 @@ enum TestEnumI64 {
     Two,
 }
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(u64)]
 enum TestEnumU64 {
@@ -70,7 +72,7 @@ enum TestEnumU64 {
     Two,
 }
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(isize)]
 enum TestEnumIsize {
@@ -78,7 +80,7 @@ enum TestEnumIsize {
     Two,
 }
 
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(usize)]
 enum TestEnumUsize {
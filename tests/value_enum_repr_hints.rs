@@ -0,0 +1,93 @@
+#![allow(dead_code)]
+
+use core::ffi::c_int;
+use e_macros::Value;
+use std::mem::{align_of, size_of};
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(C)]
+enum CEnum {
+    Negative = -1,
+    Zero,
+    One,
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(C, u8)]
+enum Tagged {
+    Empty,
+    Data(u32),
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(u16, align(8))]
+enum Aligned {
+    A,
+    B,
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(align(4))]
+enum AlignOnly {
+    A,
+}
+
+#[e_macros::value(display)]
+#[derive(Debug, PartialEq)]
+#[repr(transparent)]
+enum Transparent {
+    Only(u64),
+}
+
+#[derive(Debug, PartialEq, e_macros::Value)]
+#[repr(C)]
+enum DerivedC {
+    A,
+    B,
+}
+
+fn repr_of<E: Value<Repr = R>, R>() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_c_is_c_int() {
+        repr_of::<CEnum, c_int>();
+        repr_of::<DerivedC, c_int>();
+        assert_eq!(CEnum::INDICES, [-1, 0, 1]);
+        assert_eq!(CEnum::try_from(-1 as c_int), Ok(CEnum::Negative));
+        assert_eq!(DerivedC::B.index(), 2);
+    }
+
+    #[test]
+    fn test_primitive_wins_over_c() {
+        repr_of::<Tagged, u8>();
+        assert_eq!(Tagged::Data(7).index(), 2u8);
+    }
+
+    #[test]
+    fn test_layout_hints() {
+        repr_of::<Aligned, u16>();
+        assert_eq!(align_of::<Aligned>(), 8);
+        repr_of::<AlignOnly, i32>();
+        assert_eq!(align_of::<AlignOnly>(), 4);
+        repr_of::<Transparent, i32>();
+        assert_eq!(size_of::<Transparent>(), 8);
+    }
+
+    #[test]
+    fn test_index_type_is_repr() {
+        #[e_macros::value(index_type = i64)]
+        #[repr(u8)]
+        enum Wide {
+            A,
+        }
+        repr_of::<Wide, i64>();
+    }
+}
@@ -1,6 +1,8 @@
+#![allow(clippy::approx_constant)]
+
 use serde::{Deserialize, Serialize};
 
-#[e_macros::value]
+#[e_macros::value(display)]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TestEnumData {
     #[serde(rename = "t")]
@@ -15,7 +17,7 @@ pub enum TestEnumData {
     V5(serde_json::Value),
 }
 
-#[e_macros::value]
+#[e_macros::value(display)]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub enum TestEnum {
     #[default]
@@ -29,7 +31,7 @@ pub enum TestEnum {
     Next(Box<TestEnum>),
 }
 
-#[e_macros::value]
+#[e_macros::value(display)]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub enum TestEnum1 {
     #[default]
@@ -125,4 +127,17 @@ mod tests {
         assert_eq!(TestEnum1::from_serde(json!("Down")).unwrap(), down);
         assert_eq!(TestEnum1::from_serde(json!({"C": 42})).unwrap(), c);
     }
+
+    #[test]
+    fn test_from_serde_str_and_reader() {
+        assert_eq!(TestEnum1::from_serde_str(r#""Down""#).unwrap(), TestEnum1::Down);
+        assert_eq!(TestEnum1::from_serde_str(r#"{"C":42}"#).unwrap(), TestEnum1::C(42));
+        assert!(TestEnum1::from_serde_str(r#""Left""#).is_err());
+
+        let json = TestEnumData::V4 { x: 1, y: 2 }.to_serde().unwrap();
+        assert_eq!(
+            TestEnumData::from_serde_reader(json.as_bytes()).unwrap(),
+            TestEnumData::V4 { x: 1, y: 2 }
+        );
+    }
 }
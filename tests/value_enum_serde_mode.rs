@@ -0,0 +1,195 @@
+#![allow(dead_code)]
+
+use serde_json::json;
+
+#[e_macros::value(serde = "value", display)]
+#[derive(Debug, PartialEq)]
+enum Level {
+    #[e(value = "一", index = 10)]
+    One,
+    #[e(value = "two", alias = "2nd")]
+    Two,
+    #[e(value = "text")]
+    Text(String),
+    #[e(value = "pair")]
+    Pair(bool, f64),
+    #[e(value = "point")]
+    Point { x: u8, r#y: u8 },
+}
+
+#[e_macros::value(serde = "index", display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u16)]
+enum Code {
+    Ok = 200,
+    NotFound = 404,
+}
+
+#[e_macros::value(serde = "lenient", display)]
+#[derive(Debug, PartialEq)]
+enum Mode {
+    #[e(value = "fast", alias = "quick")]
+    Fast,
+    #[e(value = "slow")]
+    Slow,
+    #[e(value = "custom", alias = "own")]
+    Custom(u32),
+}
+
+#[e_macros::value(serde = "value", display)]
+#[derive(Debug, PartialEq)]
+enum Open {
+    #[e(value = "known")]
+    Known,
+    #[e(other)]
+    Unknown(String),
+}
+
+#[e_macros::value(serde = "value", display)]
+#[derive(Debug, PartialEq)]
+enum Wrapper<'a, T> {
+    #[e(value = "empty")]
+    Empty,
+    #[e(value = "item")]
+    Item { item: T },
+    #[e(value = "name")]
+    Name(&'a str),
+}
+
+#[e_macros::value(serde = "value", parse(case_insensitive), display)]
+#[derive(Debug, PartialEq)]
+enum Strict {
+    #[e(value = "low", alias = "l")]
+    Low,
+    #[e(value = "cc", alias = "c")]
+    Custom(u8),
+}
+
+#[e_macros::value(serde = "lenient", parse(case_insensitive), display)]
+#[derive(Debug, PartialEq)]
+enum Relaxed {
+    #[e(value = "low", alias = "l")]
+    Low,
+    #[e(value = "cc", alias = "c")]
+    Custom(u8),
+}
+
+#[e_macros::value(serde = "value", display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Flat {
+    #[e(value = "on")]
+    On,
+    #[e(value = "off")]
+    Off,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_mode() {
+        assert_eq!(Level::One.to_serde().unwrap(), r#""一""#);
+        assert_eq!(
+            Level::Text("a".to_string()).to_serde().unwrap(),
+            r#"{"text":"a"}"#
+        );
+        assert_eq!(
+            Level::Pair(true, 1.5).to_serde().unwrap(),
+            r#"{"pair":[true,1.5]}"#
+        );
+        assert_eq!(
+            Level::Point { x: 1, y: 2 }.to_serde().unwrap(),
+            r#"{"point":{"x":1,"y":2}}"#
+        );
+
+        assert_eq!(Level::from_serde(json!("一")).unwrap(), Level::One);
+        assert_eq!(
+            Level::from_serde(json!({"text": "a"})).unwrap(),
+            Level::Text("a".to_string())
+        );
+        assert_eq!(
+            Level::from_serde(json!({"pair": [true, 1.5]})).unwrap(),
+            Level::Pair(true, 1.5)
+        );
+        assert_eq!(
+            Level::from_serde(json!({"point": {"y": 2, "x": 1}})).unwrap(),
+            Level::Point { x: 1, y: 2 }
+        );
+    }
+
+    #[test]
+    fn test_value_mode_errors() {
+        assert!(Level::from_serde(json!(10)).is_err());
+        assert!(Level::from_serde(json!("One")).is_err());
+        assert!(Level::from_serde(json!("text")).is_err());
+        assert!(Level::from_serde(json!({"Text": "a"})).is_err());
+        assert!(Level::from_serde(json!({"point": {"x": 1}})).is_err());
+        assert!(Level::from_serde(json!({"text": "a", "pair": [true, 1.5]})).is_err());
+    }
+
+    #[test]
+    fn test_value_mode_is_canonical() {
+        assert!(Level::from_serde(json!("2nd")).is_err());
+        assert_eq!(Strict::from_serde(json!("low")).unwrap(), Strict::Low);
+        assert_eq!(Strict::from_serde(json!({"cc": 1})).unwrap(), Strict::Custom(1));
+        assert!(Strict::from_serde(json!("l")).is_err());
+        assert!(Strict::from_serde(json!("LOW")).is_err());
+        assert!(Strict::from_serde(json!({"c": 1})).is_err());
+        assert!(Strict::from_serde(json!({"CC": 1})).is_err());
+
+        assert_eq!(Relaxed::from_serde(json!("L")).unwrap(), Relaxed::Low);
+        assert_eq!(Relaxed::from_serde(json!({"C": 1})).unwrap(), Relaxed::Custom(1));
+        assert_eq!(Relaxed::from_serde(json!({"CC": 2})).unwrap(), Relaxed::Custom(2));
+    }
+
+    #[test]
+    fn test_non_self_describing() {
+        let bytes = bincode::serialize(&Code::NotFound).unwrap();
+        assert_eq!(bytes, 404u16.to_le_bytes());
+        assert_eq!(bincode::deserialize::<Code>(&bytes).unwrap(), Code::NotFound);
+        let bytes = bincode::serialize(&[Flat::Off, Flat::On]).unwrap();
+        assert_eq!(bincode::deserialize::<[Flat; 2]>(&bytes).unwrap(), [Flat::Off, Flat::On]);
+    }
+
+    #[test]
+    fn test_index_mode() {
+        assert_eq!(Code::NotFound.to_serde().unwrap(), "404");
+        assert_eq!(Code::from_serde(json!(200)).unwrap(), Code::Ok);
+        assert!(Code::from_serde(json!(201)).is_err());
+        assert!(Code::from_serde(json!(-1)).is_err());
+        assert!(Code::from_serde(json!(70000)).is_err());
+        assert!(Code::from_serde(json!("Ok")).is_err());
+    }
+
+    #[test]
+    fn test_lenient_mode() {
+        assert_eq!(Mode::Slow.to_serde().unwrap(), r#""slow""#);
+        assert_eq!(Mode::from_serde(json!("fast")).unwrap(), Mode::Fast);
+        assert_eq!(Mode::from_serde(json!("quick")).unwrap(), Mode::Fast);
+        assert_eq!(Mode::from_serde(json!(2)).unwrap(), Mode::Slow);
+        assert_eq!(
+            Mode::from_serde(json!({"own": 7})).unwrap(),
+            Mode::Custom(7)
+        );
+        assert_eq!(Mode::Custom(7).to_serde().unwrap(), r#"{"custom":7}"#);
+    }
+
+    #[test]
+    fn test_other_variant() {
+        let unknown = Open::Unknown("new".to_string());
+        assert_eq!(unknown.to_serde().unwrap(), r#""new""#);
+        assert_eq!(Open::from_serde(json!("new")).unwrap(), unknown);
+        assert_eq!(Open::from_serde(json!("known")).unwrap(), Open::Known);
+    }
+
+    #[test]
+    fn test_generic_round_trip() {
+        let item: Wrapper<u8> = Wrapper::Item { item: 3 };
+        assert_eq!(item.to_serde().unwrap(), r#"{"item":{"item":3}}"#);
+        let parsed: Wrapper<u8> = serde_json::from_str(r#"{"item":{"item":3}}"#).unwrap();
+        assert_eq!(parsed, item);
+        let name: Wrapper<u8> = serde_json::from_str(r#"{"name":"borrowed"}"#).unwrap();
+        assert_eq!(name, Wrapper::Name("borrowed"));
+    }
+}
@@ -0,0 +1,104 @@
+#![allow(dead_code)]
+
+use std::str::FromStr;
+
+#[e_macros::value(display)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Color {
+    #[e(value = "RED", index = 1)]
+    Red,
+    #[e(value = "GREEN")]
+    Green,
+}
+
+#[e_macros::value(no_from_str, no_as_ref, no_into_repr, no_into_str, no_eq_str, no_eq_repr, display)]
+#[derive(Debug, PartialEq)]
+enum Bare {
+    One,
+}
+
+// Each opted-out trait can be implemented by hand without conflicting
+impl FromStr for Bare {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, Self::Err> {
+        Ok(Bare::One)
+    }
+}
+
+impl AsRef<str> for Bare {
+    fn as_ref(&self) -> &str {
+        "bare"
+    }
+}
+
+impl From<Bare> for i32 {
+    fn from(_: Bare) -> Self {
+        -1
+    }
+}
+
+impl From<&Bare> for &'static str {
+    fn from(_: &Bare) -> Self {
+        "bare"
+    }
+}
+
+impl PartialEq<&str> for Bare {
+    fn eq(&self, _: &&str) -> bool {
+        true
+    }
+}
+
+impl PartialEq<i32> for Bare {
+    fn eq(&self, _: &i32) -> bool {
+        true
+    }
+}
+
+fn describe<T: AsRef<str>>(value: T) -> String {
+    value.as_ref().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("RED".parse::<Color>(), Ok(Color::Red));
+        assert_eq!(Color::from_str("GREEN"), Ok(Color::Green));
+        assert!("BLUE".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_as_ref() {
+        assert_eq!(describe(Color::Green), "GREEN");
+        assert_eq!(Color::Red.as_ref(), "RED");
+    }
+
+    #[test]
+    fn test_into_repr_and_str() {
+        let n: u8 = Color::Green.into();
+        assert_eq!(n, 2);
+        let s: &'static str = (&Color::Red).into();
+        assert_eq!(s, "RED");
+    }
+
+    #[test]
+    fn test_partial_eq() {
+        assert!(Color::Red == "RED");
+        assert!(Color::Red != "GREEN");
+        assert!(Color::Green == 2u8);
+        assert!(Color::Green != 1u8);
+    }
+
+    #[test]
+    fn test_opt_out() {
+        assert_eq!("anything".parse::<Bare>(), Ok(Bare::One));
+        assert_eq!(Bare::One.as_ref(), "bare");
+        assert_eq!(i32::from(Bare::One), -1);
+        assert!(Bare::One == "x");
+        assert_eq!(Bare::try_from("One"), Ok(Bare::One));
+    }
+}
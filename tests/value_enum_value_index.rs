@@ -1,7 +1,7 @@
 #![allow(unused)]
 use e_macros::value;
 use std::fmt;
-#[value]
+#[value(display)]
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 #[repr(i8)]
 enum TestEnum {
@@ -21,7 +21,7 @@ enum TestEnum {
     EmptyValue,
     #[e(index = 127)]
     MaxI8Index,
-    #[e(value = "Object")]
+    #[e(value = "Object", index = -1)]
     Vlaue {
         index: i32,
         value: String,
@@ -51,7 +51,7 @@ mod tests {
                 value: "".to_string()
             }
             .index(),
-            127
+            -1
         );
     }
 
@@ -95,7 +95,7 @@ mod tests {
             index: 42,
             value: "test".to_string(),
         };
-        assert_eq!(value.index(), 127);
+        assert_eq!(value.index(), -1);
         assert_eq!(value.value(), "Object");
     }
 